@@ -1,4 +1,43 @@
-use std::sync::atomic::AtomicUsize;
+use std::{collections::HashMap, sync::atomic::AtomicUsize};
+
+/// Valor de um atributo extra de aresta
+#[derive(Debug, Clone, PartialEq)]
+#[allow(unused)]
+pub enum EdgeAttrValue {
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+/// Atributos extras de uma aresta, indexados pelo nome
+pub type EdgeAttrs = HashMap<String, EdgeAttrValue>;
+
+/// Como combinar o peso de uma nova aresta com o de uma aresta já existente
+/// entre os mesmos vértices (ver `DiGraph::add_or_update_edge`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(unused)]
+pub enum ParallelCombine {
+    /// Mantém o menor peso
+    Min,
+    /// Mantém o maior peso
+    Max,
+    /// Soma os pesos
+    Sum,
+    /// Substitui pelo novo peso
+    Replace,
+}
+
+impl ParallelCombine {
+    /// Combina o peso `current` com o peso `new`.
+    pub fn combine(&self, current: i32, new: i32) -> i32 {
+        match self {
+            ParallelCombine::Min => current.min(new),
+            ParallelCombine::Max => current.max(new),
+            ParallelCombine::Sum => current.saturating_add(new),
+            ParallelCombine::Replace => new,
+        }
+    }
+}
 
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -63,6 +102,16 @@ impl Edge {
         (self.origin_key, self.destiny_key)
     }
 
+    /// Retorna uma cópia da aresta com novas extremidades, mantendo id e peso.
+    pub fn rewired(&self, origin_vertice: i32, destiny_vertice: i32) -> Edge {
+        Edge {
+            id: self.id,
+            destiny_key: destiny_vertice,
+            origin_key: origin_vertice,
+            weight: self.weight,
+        }
+    }
+
 }
 
 impl PartialOrd for Edge {
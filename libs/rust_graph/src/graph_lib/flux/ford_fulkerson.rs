@@ -163,13 +163,14 @@ pub fn max_flux(g: &DiGraph, s: i32, t: i32) -> (FluxMap, ResidualGraph) {
     let vertice = g
         .get_vertice_arc(s)
         .expect("Vértice de origem não encontrado");
-    let edges = vertice.edges_vec_ref();
-    for e in edges {
-        let (v, w) = (e.origin_key(), e.destiny_key());
-        if let Some(att) = flux_map.get(&(v, w)) {
-            flux_map.set_max_flux(att.get_flux() as i32);
+    // Soma o fluxo de cada par (s, w); arestas paralelas compartilham o mesmo atributo
+    let mut total = 0;
+    for w in g.get_sucessor(vertice.key()).unwrap_or_default() {
+        if let Some(att) = flux_map.get(&(s, w)) {
+            total += att.get_flux() as i32;
         }
     }
+    flux_map.set_max_flux(total);
 
     (flux_map, residual_graph)
 }
@@ -196,3 +197,31 @@ impl IncreasingPath {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_flux_sums_every_edge_leaving_the_source() {
+        // A fonte tem três saídas; o fluxo máximo é a soma delas, não a da última visitada
+        let g = DiGraph::from_weighted_edges(&[
+            (0, 1, 3),
+            (0, 2, 2),
+            (0, 3, 4),
+            (1, 4, 3),
+            (2, 4, 2),
+            (3, 4, 1),
+        ]);
+        let (flux_map, _) = max_flux(&g, 0, 4);
+        assert_eq!(flux_map.get_max_flux(), 6);
+    }
+
+    #[test]
+    fn max_flux_single_source_edge() {
+        let g =
+            DiGraph::from_weighted_edges(&[(0, 1, 5), (1, 2, 3), (1, 3, 4), (2, 4, 9), (3, 4, 1)]);
+        let (flux_map, _) = max_flux(&g, 0, 4);
+        assert_eq!(flux_map.get_max_flux(), 4);
+    }
+}
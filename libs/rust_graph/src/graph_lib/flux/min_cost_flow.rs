@@ -0,0 +1,186 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    graph_lib::edge::{Edge, EdgeAttrValue},
+    DiGraph,
+};
+
+/// Arco da rede residual. Os arcos são guardados em pares: `i` é o arco direto
+/// e `i ^ 1` o reverso, de custo oposto.
+struct FlowArc {
+    to: usize,
+    capacity: i64,
+    cost: i64,
+}
+
+/// Rede residual indexada por posição, usada pelos caminhos aumentantes de custo mínimo
+struct CostNetwork {
+    arcs: Vec<FlowArc>,
+    adj: Vec<Vec<usize>>,
+}
+
+impl CostNetwork {
+    fn new(len: usize) -> Self {
+        CostNetwork {
+            arcs: Vec::new(),
+            adj: vec![Vec::new(); len],
+        }
+    }
+
+    fn add_arc(&mut self, from: usize, to: usize, capacity: i64, cost: i64) {
+        self.adj[from].push(self.arcs.len());
+        self.arcs.push(FlowArc { to, capacity, cost });
+        self.adj[to].push(self.arcs.len());
+        self.arcs.push(FlowArc {
+            to: from,
+            capacity: 0,
+            cost: -cost,
+        });
+    }
+
+    /// Caminho de custo mínimo na rede residual (Bellman-Ford com fila).
+    ///
+    /// Retorna o custo e o arco usado para chegar em cada vértice.
+    fn cheapest_path(&self, s: usize, t: usize) -> Option<(i64, Vec<Option<usize>>)> {
+        let len = self.adj.len();
+        let mut dist: Vec<Option<i64>> = vec![None; len];
+        let mut pred_arc: Vec<Option<usize>> = vec![None; len];
+        let mut in_queue = vec![false; len];
+        let mut queue = VecDeque::from([s]);
+        dist[s] = Some(0);
+
+        while let Some(v) = queue.pop_front() {
+            in_queue[v] = false;
+            let v_dist = dist[v].unwrap();
+            for &a in self.adj[v].iter() {
+                let arc = &self.arcs[a];
+                if arc.capacity <= 0 {
+                    continue;
+                }
+                let candidate = v_dist + arc.cost;
+                if dist[arc.to].is_none_or(|d| candidate < d) {
+                    dist[arc.to] = Some(candidate);
+                    pred_arc[arc.to] = Some(a);
+                    if !in_queue[arc.to] {
+                        in_queue[arc.to] = true;
+                        queue.push_back(arc.to);
+                    }
+                }
+            }
+        }
+        dist[t].map(|cost| (cost, pred_arc))
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Fluxo máximo de custo mínimo entre `source` e `sink`.
+    ///
+    /// Mapeamento dos atributos das arestas:
+    /// - `weight` é o **custo** por unidade de fluxo
+    /// - a **capacidade** é o atributo `"capacity"` (`EdgeAttrValue::Int`) da aresta;
+    ///   sem ele a aresta tem capacidade 1 (arestas paralelas somam capacidade)
+    ///
+    /// Para outras capacidades use `min_cost_max_flow_with`.
+    ///
+    /// Retorna `(fluxo máximo, custo mínimo)`.
+    pub fn min_cost_max_flow(&self, source: i32, sink: i32) -> (i64, i64) {
+        self.min_cost_max_flow_with(source, sink, |e| {
+            match self.get_edge_attr(e.id(), "capacity") {
+                Some(EdgeAttrValue::Int(capacity)) => *capacity,
+                _ => 1,
+            }
+        })
+    }
+
+    /// Fluxo máximo de custo mínimo usando `capacity` para obter a capacidade de cada aresta.
+    ///
+    /// O peso da aresta continua sendo o custo; capacidades negativas valem 0.
+    /// Usa caminhos aumentantes sucessivos:
+    /// a cada passo aumenta o fluxo pelo caminho residual mais barato (Bellman-Ford com fila,
+    /// que aceita os custos negativos dos arcos reversos).
+    ///
+    /// A rede não pode ter ciclos de custo negativo.
+    pub fn min_cost_max_flow_with<F>(&self, source: i32, sink: i32, capacity: F) -> (i64, i64)
+    where
+        F: Fn(&Edge) -> i64,
+    {
+        if source == sink || !self.vertice_exists(source) || !self.vertice_exists(sink) {
+            return (0, 0);
+        }
+        let keys = self.vertices_sorted();
+        let index: HashMap<i32, usize> = keys.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+
+        let mut network = CostNetwork::new(keys.len());
+        for e in self.edges_sorted() {
+            let (v, w) = e.v_w();
+            network.add_arc(index[&v], index[&w], capacity(&e).max(0), e.weight() as i64);
+        }
+
+        let (s, t) = (index[&source], index[&sink]);
+        let (mut flow, mut cost) = (0, 0);
+        while let Some((path_cost, pred_arc)) = network.cheapest_path(s, t) {
+            // Gargalo do caminho
+            let mut bottleneck = i64::MAX;
+            let mut v = t;
+            while let Some(a) = pred_arc[v] {
+                bottleneck = bottleneck.min(network.arcs[a].capacity);
+                v = network.arcs[a ^ 1].to;
+            }
+
+            let mut v = t;
+            while let Some(a) = pred_arc[v] {
+                network.arcs[a].capacity -= bottleneck;
+                network.arcs[a ^ 1].capacity += bottleneck;
+                v = network.arcs[a ^ 1].to;
+            }
+            flow += bottleneck;
+            cost += bottleneck * path_cost;
+        }
+        (flow, cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graph_lib::edge::{Edge, EdgeAttrValue},
+        DiGraph,
+    };
+
+    /// Adiciona `v -> w` com custo `cost` e o atributo `"capacity"`
+    fn add_arc(g: &mut DiGraph, v: i32, w: i32, capacity: i64, cost: i32) {
+        let e = Edge::new_weighted(v, w, cost);
+        let id = e.id();
+        g.add_edge(e);
+        g.set_edge_attr(id, "capacity", EdgeAttrValue::Int(capacity));
+    }
+
+    #[test]
+    fn min_cost_max_flow_known_value() {
+        // Fluxo 3 satura a saída de 0 e a entrada de 3, o que força custo 10
+        let mut g = DiGraph::new();
+        add_arc(&mut g, 0, 1, 2, 1);
+        add_arc(&mut g, 0, 2, 1, 2);
+        add_arc(&mut g, 1, 2, 1, 1);
+        add_arc(&mut g, 1, 3, 1, 3);
+        add_arc(&mut g, 2, 3, 2, 1);
+        assert_eq!(g.min_cost_max_flow(0, 3), (3, 10));
+        assert_eq!(g.min_cost_max_flow(3, 0), (0, 0));
+    }
+
+    #[test]
+    fn min_cost_max_flow_keeps_capacities_above_u32() {
+        let mut g = DiGraph::new();
+        add_arc(&mut g, 0, 1, 1 << 32, 1);
+        assert_eq!(g.min_cost_max_flow(0, 1), (1 << 32, 1 << 32));
+    }
+
+    #[test]
+    fn min_cost_max_flow_with_defaults_to_parallel_unit_edges() {
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 5), (0, 1, 2), (1, 2, 1), (1, 2, 1)]);
+        assert_eq!(g.min_cost_max_flow(0, 2), (2, 9));
+        assert_eq!(g.min_cost_max_flow_with(0, 2, |_| 1), (2, 9));
+        assert_eq!(g.min_cost_max_flow_with(0, 2, |_| -4), (0, 0));
+    }
+}
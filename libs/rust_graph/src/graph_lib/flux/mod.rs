@@ -1,3 +1,4 @@
 pub mod ford_fulkerson;
 pub mod flux_map;
-pub mod edge_atribute;
\ No newline at end of file
+pub mod edge_atribute;
+pub mod min_cost_flow;
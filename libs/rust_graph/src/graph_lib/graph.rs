@@ -1,18 +1,18 @@
 use super::{
-    edge::Edge,
-    search::busca::{DeepFirstSearch, DfsStruct, EdgeClassification},
+    edge::{Edge, EdgeAttrValue, EdgeAttrs, ParallelCombine},
+    search::busca::{DeepFirstSearch, DfsStruct, EdgeClassification, NeighborOrder},
     vertice::{self, Vertice},
 };
 use core::panic;
 use rand::{random, Rng};
 use scan_fmt::scan_fmt;
 use std::{
-    collections::{HashMap, HashSet},
-    fmt::Debug,
+    cmp::Reverse,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fmt::{self, Debug},
     fs,
     io::ErrorKind,
-    rc::Rc,
-    sync::{atomic::AtomicI32, Arc, RwLock},
+    sync::atomic::{AtomicI32, AtomicU64, Ordering},
 };
 
 #[derive(Debug)]
@@ -24,12 +24,43 @@ use std::{
 /// `edges_len`: quantidade de arestas em um grafo
 ///
 /// `vertices`: HashMap para encontrar vértices usando sua key em O(1)
+///
+/// `edge_attrs`: atributos extras das arestas (rótulo, capacidade, cor...) indexados pelo id da aresta
+///
+/// `instance`: identificador único desta instância (clones recebem um novo), ver `instance_id`
+///
+/// ## Concorrência
+/// Os vértices e arestas são armazenados por valor (sem `Rc`/`RefCell`), então `DiGraph`
+/// é `Send + Sync`: um `Arc<DiGraph>` pode ser compartilhado entre threads que executam
+/// algoritmos somente leitura (BFS, Dijkstra, Bellman-Ford...) ao mesmo tempo.
+/// Mutações exigem `&mut DiGraph`, garantindo acesso exclusivo pelo próprio compilador.
 pub struct DiGraph {
     vertices_len: u32,
     edges_len: usize,
-    vertices: HashMap<i32, Vertice>, // thread safety
+    vertices: HashMap<i32, Vertice>,
+    edge_attrs: HashMap<usize, EdgeAttrs>,
+    version: u64,
+    instance: u64,
 }
 
+/// Próximo identificador de instância (ver `DiGraph::instance_id`)
+static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(0);
+
+fn next_instance() -> u64 {
+    NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Converte um peso calculado em `i64` para `i32`, saturando nos limites
+fn saturate_weight(weight: i64) -> i32 {
+    weight.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+// Garante em tempo de compilação que o grafo pode ser compartilhado entre threads
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<DiGraph>();
+};
+
 #[allow(unused)]
 impl DiGraph {
     /// Cria um novo grafo direcionado vazio.
@@ -38,6 +69,9 @@ impl DiGraph {
             vertices_len: 0,
             edges_len: 0,
             vertices: HashMap::new(),
+            edge_attrs: HashMap::new(),
+            version: 0,
+            instance: next_instance(),
         }
     }
 
@@ -47,6 +81,9 @@ impl DiGraph {
             vertices_len: 0,
             edges_len: 0,
             vertices: HashMap::with_capacity(vertice_num as usize),
+            edge_attrs: HashMap::new(),
+            version: 0,
+            instance: next_instance(),
         }
     }
 
@@ -59,6 +96,9 @@ impl DiGraph {
     /// <origem2> <destino2>
     /// ...
     /// ```
+    ///
+    /// Linhas em branco e linhas começando com `#` ou `%` (comentários) são ignoradas;
+    /// o cabeçalho é a primeira linha que não é comentário.
     pub fn from_file(file_path: &str) -> Option<DiGraph> {
         let file = fs::read_to_string(file_path);
         let Ok(file_content) = file else {
@@ -73,9 +113,14 @@ impl DiGraph {
             return None;
         };
 
-        let mut lines = file_content.lines(); // Iterador do arquivo
+        // Iterador do arquivo: (índice da linha, conteúdo), sem comentários e linhas em branco
+        let mut lines = file_content.lines().enumerate().filter(|(_, line)| {
+            let line = line.trim_start();
+            !(line.is_empty() || line.starts_with('#') || line.starts_with('%'))
+        });
 
-        let (vert_num, edge_num) = match scan_fmt!(lines.next().unwrap_or(""), "{} {}", u32, u32) {
+        let header = lines.next().map_or("", |(_, line)| line);
+        let (vert_num, edge_num) = match scan_fmt!(header, "{} {}", u32, u32) {
             Ok((v, e)) => (v, e),
             Err(err) => {
                 eprintln!("Erro de leitura: {err}\nO arquivo pode não estar no formato requerido.");
@@ -83,43 +128,91 @@ impl DiGraph {
             }
         };
 
-        let mut graph = DiGraph::new();
+        // O cabeçalho só serve de estimativa para pré-alocar; o conteúdo é que vale. Cada aresta
+        // ocupa ao menos 4 bytes ("1 2\n") e cita no máximo 2 vértices, o que limita a estimativa
+        let max_edges = file_content.len() / 4;
+        let max_vertices = u32::try_from(2 * max_edges).unwrap_or(u32::MAX);
+        let mut graph = DiGraph::new_sized(vert_num.min(max_vertices));
+        let mut edges = Vec::with_capacity((edge_num as usize).min(max_edges));
 
-        for (index, line) in lines.enumerate() {
+        for (index, line) in lines {
             let (orig, dest) = match scan_fmt!(line, "{} {}", i32, i32) {
                 Ok(tuple) => tuple,
                 Err(err) => {
                     println!(
                         "Erro {{{err}}} durante a leitura de aresta na linha {}\nconteúdo: {line}",
-                        index + 2
+                        index + 1
                     );
                     return None;
                 }
             };
-            graph.add_edge(Edge::new(orig, dest));
+            edges.push(Edge::new(orig, dest));
         }
+        graph.add_edges_bulk(edges);
         Some(graph)
     }
 
-    /// Cria um grafo direcionado a partir de um vetor de arestas ponderadas ou não.
-    pub fn from_edges(edge_array: Vec<Edge>) -> DiGraph {
-        let mut graph = DiGraph::new();
+    /// Insere muitas arestas de uma vez, com o mesmo resultado de chamar `add_edge` em ordem.
+    ///
+    /// Faz uma única consulta ao `HashMap` por aresta (no destino, criando o vértice se
+    /// faltar) e agrupa as arestas por origem para buscar cada vértice de origem uma só vez.
+    /// A ordenação é estável, então arestas paralelas mantêm a ordem de inserção.
+    pub(crate) fn add_edges_bulk(&mut self, mut edges: Vec<Edge>) {
+        for e in edges.iter() {
+            self.vertice_entry(e.destiny_key()).add_back_edge(e.clone());
+        }
 
-        edge_array.iter().for_each(|edge| {
-            let (v, w) = (edge.origin_key(), edge.destiny_key());
-            if !graph.vertice_exists(v) {
-                graph.add_vertice(v);
+        edges.sort_by_key(|e| e.origin_key());
+        self.edges_len += edges.len();
+        let mut edges = edges.into_iter().peekable();
+        while let Some(first) = edges.next() {
+            let origin_key = first.origin_key();
+            let origin = self.vertice_entry(origin_key);
+            origin.add_edge(first);
+            while let Some(e) = edges.next_if(|e| e.origin_key() == origin_key) {
+                origin.add_edge(e);
             }
-            if !graph.vertice_exists(w) {
-                graph.add_vertice(w);
+        }
+        self.version += 1;
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
+    }
+
+    /// Vértice `key`, criado se ainda não existir (sem alterar `version`).
+    fn vertice_entry(&mut self, key: i32) -> &mut Vertice {
+        match self.vertices.entry(key) {
+            Entry::Occupied(slot) => slot.into_mut(),
+            Entry::Vacant(slot) => {
+                self.vertices_len += 1;
+                slot.insert(Vertice::new(key))
             }
-            let vertice = graph.get_vertice_arc_mut(v).unwrap();
-            vertice.add_edge(edge.clone());
-            graph.edges_len += 1;
-        });
+        }
+    }
+
+    /// Cria um grafo direcionado a partir de um vetor de arestas ponderadas ou não.
+    pub fn from_edges(edge_array: Vec<Edge>) -> DiGraph {
+        let mut graph = DiGraph::new();
+        graph.add_edges_bulk(edge_array);
         graph
     }
 
+    /// Cria um grafo direcionado a partir de uma lista de pares `(origem, destino)`.
+    ///
+    /// Todos os vértices citados são criados e as arestas recebem peso 1.
+    pub fn from_edge_list(edges: &[(i32, i32)]) -> DiGraph {
+        DiGraph::from_edges(edges.iter().map(|&(v, w)| Edge::new(v, w)).collect())
+    }
+
+    /// Cria um grafo direcionado a partir de uma lista de triplas `(origem, destino, peso)`.
+    pub fn from_weighted_edges(edges: &[(i32, i32, i32)]) -> DiGraph {
+        DiGraph::from_edges(
+            edges
+                .iter()
+                .map(|&(v, w, weight)| Edge::new_weighted(v, w, weight))
+                .collect(),
+        )
+    }
+
     /// Retorna a quantidade de vértices no grafo.
     pub fn vertices_length(&self) -> usize {
         self.vertices.len()
@@ -137,12 +230,23 @@ impl DiGraph {
         self.vertices.keys().cloned().collect()
     }
 
-    /// Retorna a referência do Arc<RwLock<Vertice>>, se existir.
+    /// Retorna as chaves dos vértices em ordem crescente.
+    ///
+    /// Diferente de `get_vertice_key_array`, a ordem não depende do `HashMap`.
+    pub fn vertices_sorted(&self) -> Vec<i32> {
+        let mut keys = self.get_vertice_key_array();
+        keys.sort();
+        keys
+    }
+
+    /// Retorna a referência do vértice, se existir.
     pub fn get_vertice_arc(&self, vertice_key: i32) -> Option<&Vertice> {
         self.vertices.get(&vertice_key)
     }
 
+    /// Acesso mutável a um vértice. Conta como modificação do grafo (ver `version`).
     pub fn get_vertice_arc_mut(&mut self, vertice_key: i32) -> Option<&mut Vertice> {
+        self.version += 1;
         self.vertices.get_mut(&vertice_key)
     }
 
@@ -159,9 +263,87 @@ impl DiGraph {
         edges
     }
 
+    /// Retorna todas as arestas do grafo em ordem determinística.
+    ///
+    /// Ordena por origem, depois por destino e por fim pelo id da aresta.
+    pub fn edges_sorted(&self) -> Vec<Edge> {
+        let mut edges = self.all_edges();
+        edges.sort_by_key(|e| (e.origin_key(), e.destiny_key(), e.id()));
+        edges
+    }
+
+    /// Retorna a aresta de maior peso, `None` se o grafo não tiver arestas.
+    ///
+    /// Empates ficam com a aresta de menor id.
+    pub fn max_weight_edge(&self) -> Option<Edge> {
+        self.all_edges()
+            .into_iter()
+            .min_by_key(|e| (Reverse(e.weight()), e.id()))
+    }
+
+    /// Retorna a aresta de menor peso, `None` se o grafo não tiver arestas.
+    ///
+    /// Empates ficam com a aresta de menor id.
+    pub fn min_weight_edge(&self) -> Option<Edge> {
+        self.all_edges()
+            .into_iter()
+            .min_by_key(|e| (e.weight(), e.id()))
+    }
+
+    /// Retorna o menor peso de aresta, `None` se o grafo não tiver arestas.
+    pub fn min_weight(&self) -> Option<i32> {
+        self.vertices
+            .values()
+            .flat_map(|v| v.edges_vec_ref())
+            .map(|e| e.weight())
+            .min()
+    }
+
+    /// Verifica se alguma aresta tem peso negativo.
+    ///
+    /// Útil para escolher entre Dijkstra (pesos não negativos) e Bellman-Ford.
+    pub fn has_negative_weights(&self) -> bool {
+        self.min_weight().is_some_and(|w| w < 0)
+    }
+
+    /// Remove as arestas de `e.origin_key()` para `e.destiny_key()` com o mesmo peso de `e`,
+    /// junto com suas arestas de retorno e seus atributos.
     pub fn remove_edge(&mut self, e: Edge) {
-        let mut vertice = self.get_vertice_arc_mut(e.origin_key()).unwrap();
-        vertice.remove_edge(e);
+        let (v, w) = e.v_w();
+        let ids: Vec<usize> = self
+            .get_edges(v, w)
+            .unwrap_or_default()
+            .iter()
+            .filter(|edge| edge.weight() == e.weight())
+            .map(|edge| edge.id())
+            .collect();
+
+        for id in ids {
+            self.remove_edge_with_id(v, w, id);
+        }
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
+    }
+
+    /// Mantém apenas as arestas que satisfazem `pred`, removendo as demais
+    /// (junto com suas arestas de retorno e seus atributos). Nenhum vértice é removido.
+    pub fn retain_edges<F>(&mut self, pred: F)
+    where
+        F: Fn(&Edge) -> bool,
+    {
+        let removed: Vec<Edge> = self.all_edges().into_iter().filter(|e| !pred(e)).collect();
+        for e in removed {
+            self.remove_edge_with_id(e.origin_key(), e.destiny_key(), e.id());
+        }
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
+    }
+
+    fn remove_edge_with_id(&mut self, v: i32, w: i32, id: usize) {
+        self.get_vertice_arc_mut(v).unwrap().remove_edge_by_id(w, id);
+        self.get_vertice_arc_mut(w).unwrap().remove_back_edge_by_id(v, id);
+        self.edge_attrs.remove(&id);
+        self.edges_len -= 1;
     }
 
     /// Aplica uma função a todas as arestas do grafo.
@@ -171,6 +353,7 @@ impl DiGraph {
     where
         F: Fn(&mut Edge) -> (),
     {
+        self.version += 1;
         for v in self.vertices.values_mut() {
             let mut_edges = v.edges_hashmap_mut();
             for edge_vec in mut_edges.values_mut() {
@@ -179,6 +362,59 @@ impl DiGraph {
                 }
             }
         }
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
+    }
+
+    /// Ordena uma única vez as arestas de todos os vértices (ver `Vertice::sort_edges`).
+    ///
+    /// Cada vértice guarda suas arestas sucessoras na ordem (destino, id) usada pelas buscas,
+    /// e a busca em profundidade em ordem crescente percorre essa lista sem ordenar a cada
+    /// visita. Não altera o grafo (`version` não muda), mas a ordem de um vértice é descartada
+    /// quando suas arestas mudam: rode de novo depois das mutações.
+    pub fn sort_all_edges(&mut self) {
+        for vertice in self.vertices.values_mut() {
+            vertice.sort_edges();
+        }
+    }
+
+    /// Multiplica o peso de todas as arestas por `factor`, sem recriar o grafo.
+    ///
+    /// O resultado satura nos limites de `i32` (`i32::MIN` / `i32::MAX`) em vez de estourar.
+    pub fn scale_weights(&mut self, factor: i64) {
+        self.map_weights(|w| w.saturating_mul(factor));
+    }
+
+    /// Soma `delta` ao peso de todas as arestas, sem recriar o grafo.
+    ///
+    /// O resultado satura nos limites de `i32` (`i32::MIN` / `i32::MAX`) em vez de estourar.
+    pub fn offset_weights(&mut self, delta: i64) {
+        self.map_weights(|w| w.saturating_add(delta));
+    }
+
+    /// Aplica `f` ao peso de todas as arestas, sem recriar o grafo (ex: negar, limitar, log).
+    ///
+    /// `f` recebe e devolve o peso em `i64`; o resultado satura nos limites de `i32`.
+    pub fn map_weights(&mut self, f: impl Fn(i64) -> i64) {
+        self.update_weights(|w| saturate_weight(f(w as i64)));
+    }
+
+    /// Aplica `f` ao peso de cada aresta, mantendo as cópias em `back_edges` iguais.
+    fn update_weights<F>(&mut self, f: F)
+    where
+        F: Fn(i32) -> i32,
+    {
+        self.version += 1;
+        for v in self.vertices.values_mut() {
+            for edge in v.edges_vec_mut() {
+                edge.set_weight(f(edge.weight()));
+            }
+            for edge in v.back_edges_vec_mut() {
+                edge.set_weight(f(edge.weight()));
+            }
+        }
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
     }
 
     /// Verifica a existência de um vértice no grafo.
@@ -188,6 +424,19 @@ impl DiGraph {
         self.vertices.contains_key(&vert_key)
     }
 
+    /// Inverte a direção de todas as arestas sem criar um novo grafo.
+    ///
+    /// Equivale a `transpose`, mas reaproveita a estrutura atual: ids, pesos, atributos
+    /// e contagens de vértices e arestas são mantidos.
+    pub fn reverse_in_place(&mut self) {
+        self.version += 1;
+        for vertice in self.vertices.values_mut() {
+            vertice.reverse();
+        }
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
+    }
+
     /// Adiciona um vértice ao grafo.
     pub fn add_vertice(&mut self, vertice_key: i32) -> bool {
         if self.vertice_exists(vertice_key) {
@@ -196,6 +445,93 @@ impl DiGraph {
         let vertice = Vertice::new(vertice_key);
         self.vertices.insert(vertice_key, vertice);
         self.vertices_len += 1;
+        self.version += 1;
+        #[cfg(debug_assertions)]
+        self.debug_assert_insertion(None);
+        true
+    }
+
+    /// Remove um vértice e todas as arestas que chegam ou saem dele.
+    ///
+    /// Retorna `false` se o vértice não existir.
+    pub fn remove_vertice(&mut self, vertice_key: i32) -> bool {
+        self.remove_vertice_returning(vertice_key).is_some()
+    }
+
+    /// Remove um vértice e retorna as arestas que estavam ligadas a ele.
+    ///
+    /// A tupla segue o formato de `Vertice::get_all_edges_tuple`: arestas que saíam e arestas
+    /// que chegavam no vértice (um laço aparece nas duas). `None` se o vértice não existir.
+    pub fn remove_vertice_returning(&mut self, vertice_key: i32) -> Option<(Vec<Edge>, Vec<Edge>)> {
+        let (edges, back_edges) = self.detach_vertice(vertice_key)?;
+        for e in edges.iter().chain(back_edges.iter()) {
+            self.edge_attrs.remove(&e.id());
+        }
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
+        Some((edges, back_edges))
+    }
+
+    /// Retira um vértice do grafo sem apagar os atributos das suas arestas.
+    ///
+    /// Retorna as arestas que saíam e chegavam no vértice.
+    fn detach_vertice(&mut self, vertice_key: i32) -> Option<(Vec<Edge>, Vec<Edge>)> {
+        let vertice = self.vertices.remove(&vertice_key)?;
+        self.vertices_len -= 1;
+        self.version += 1;
+
+        let (edges, back_edges) = vertice.get_all_edges_tuple();
+        for e in edges.iter() {
+            let w = e.destiny_key();
+            if let Some(destiny) = self.vertices.get_mut(&w) {
+                destiny.remove_back_edge_by_id(vertice_key, e.id());
+            }
+        }
+        for e in back_edges.iter() {
+            let v = e.origin_key();
+            if let Some(origin) = self.vertices.get_mut(&v) {
+                origin.remove_edge_by_id(vertice_key, e.id());
+            }
+        }
+        // Laços aparecem nas duas listas, mas são uma única aresta
+        let loops = back_edges.iter().filter(|e| e.origin_key() == vertice_key).count();
+        self.edges_len -= edges.len() + back_edges.len() - loops;
+        Some((edges, back_edges))
+    }
+
+    /// Funde o vértice `merge_in` em `keep`.
+    ///
+    /// Todas as arestas de `merge_in` passam a sair de/chegar em `keep`, mantendo id, peso
+    /// e arestas paralelas. Arestas entre os dois vértices (e laços de `merge_in`) virariam
+    /// laços em `keep` e são descartadas. Por fim, `merge_in` é removido.
+    ///
+    /// Retorna `false` se algum dos vértices não existir ou se forem o mesmo vértice.
+    pub fn merge_vertices(&mut self, keep: i32, merge_in: i32) -> bool {
+        if keep == merge_in || !self.vertice_exists(keep) {
+            return false;
+        }
+        let Some((edges, back_edges)) = self.detach_vertice(merge_in) else {
+            return false;
+        };
+
+        for e in edges {
+            let w = e.destiny_key();
+            if w != keep && w != merge_in {
+                self.add_edge(e.rewired(keep, w));
+            } else {
+                self.edge_attrs.remove(&e.id());
+            }
+        }
+        for e in back_edges {
+            let v = e.origin_key();
+            if v != keep && v != merge_in {
+                self.add_edge(e.rewired(v, keep));
+            } else {
+                self.edge_attrs.remove(&e.id());
+            }
+        }
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
         true
     }
 
@@ -209,7 +545,7 @@ impl DiGraph {
             self.add_vertice(w);
         }
 
-        // Obtém o Arc<RwLock<Vertice>> referente ao vértice de origem
+        // Obtém o vértice de origem
         let vertice_origem = self.get_vertice_arc_mut(v).unwrap();
         vertice_origem.add_edge(edge.clone());
 
@@ -217,6 +553,39 @@ impl DiGraph {
         vertice_destino.add_back_edge(edge);
 
         self.edges_len += 1;
+        self.version += 1;
+        #[cfg(debug_assertions)]
+        self.debug_assert_insertion(Some((v, w)));
+    }
+
+    /// Adiciona a aresta `origin -> destiny`, ou combina o peso com a aresta já existente.
+    ///
+    /// Se já houver aresta entre os vértices, nenhuma aresta paralela é criada: o peso da
+    /// aresta de menor id é atualizado segundo `policy` (id e atributos são mantidos).
+    pub fn add_or_update_edge(
+        &mut self,
+        origin: i32,
+        destiny: i32,
+        weight: i32,
+        policy: ParallelCombine,
+    ) {
+        let existing = self
+            .get_edges(origin, destiny)
+            .and_then(|edges| edges.into_iter().min_by_key(|e| e.id()));
+        let Some(edge) = existing else {
+            self.add_edge(Edge::new_weighted(origin, destiny, weight));
+            return;
+        };
+
+        let combined = policy.combine(edge.weight(), weight);
+        self.get_vertice_arc_mut(origin)
+            .unwrap()
+            .set_edge_weight_by_id(destiny, edge.id(), combined);
+        self.get_vertice_arc_mut(destiny)
+            .unwrap()
+            .set_back_edge_weight_by_id(origin, edge.id(), combined);
+        #[cfg(debug_assertions)]
+        self.debug_assert_consistent();
     }
 
     /// Verifica se existe pelo menos uma aresta entre dois vértices.
@@ -237,6 +606,83 @@ impl DiGraph {
         None
     }
 
+    /// Pesos de todas as arestas (inclusive paralelas) de `origin` para `destiny`,
+    /// na ordem de criação (id). Vazio se não houver aresta.
+    pub fn edge_weights_between(&self, origin: i32, destiny: i32) -> Vec<i64> {
+        let mut edges = self.get_edges(origin, destiny).unwrap_or_default();
+        edges.sort_by_key(|e| e.id());
+        edges.iter().map(|e| e.weight() as i64).collect()
+    }
+
+    /// Verifica se o grafo é simples: sem arestas paralelas e sem laços.
+    pub fn is_simple(&self) -> bool {
+        self.vertices.values().all(|vertice| {
+            vertice
+                .edges_hashmap()
+                .iter()
+                .all(|(&(v, w), edges)| v != w && edges.len() <= 1)
+        })
+    }
+
+    /// Pares `(origem, destino)` com mais de uma aresta, junto com a multiplicidade.
+    ///
+    /// Pares opostos (`v -> w` e `w -> v`) são contados separadamente.
+    /// Ordenado pelo par, para auditar multigrafos importados.
+    pub fn parallel_edge_pairs(&self) -> Vec<((i32, i32), usize)> {
+        let mut pairs: Vec<((i32, i32), usize)> = self
+            .vertices
+            .values()
+            .flat_map(|vertice| vertice.edges_hashmap().iter())
+            .filter(|(_, edges)| edges.len() > 1)
+            .map(|(&pair, edges)| (pair, edges.len()))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    /// Verifica se um vértice está isolado (sem arestas que saem ou chegam).
+    ///
+    /// Retorna `None` se o vértice não existir.
+    pub fn is_isolated(&self, vertice_key: i32) -> Option<bool> {
+        let vertice = self.get_vertice_arc(vertice_key)?;
+        Some(vertice.out_degree() == 0 && vertice.in_degree() == 0)
+    }
+
+    /// Retorna as chaves de todos os vértices isolados em ordem crescente.
+    pub fn isolated_vertices(&self) -> Vec<i32> {
+        self.vertices_sorted()
+            .into_iter()
+            .filter(|&v| self.is_isolated(v) == Some(true))
+            .collect()
+    }
+
+    /// Verifica se este grafo é subgrafo de `other`, usando as mesmas chaves.
+    ///
+    /// Todo vértice deve existir em `other` e, para cada par (v, w), as arestas de `other`
+    /// devem conter os mesmos pesos com pelo menos a mesma multiplicidade (arestas paralelas).
+    pub fn is_subgraph_of(&self, other: &DiGraph) -> bool {
+        self.vertices.values().all(|vertice| {
+            let Some(other_vertice) = other.get_vertice_arc(vertice.key()) else {
+                return false;
+            };
+            vertice.edges_hashmap().iter().all(|(&(_, w), edges)| {
+                let Some(other_edges) = other_vertice.get_edges_to(w) else {
+                    return false;
+                };
+                let mut available: Vec<i32> = other_edges.iter().map(|e| e.weight()).collect();
+                edges.iter().all(|e| {
+                    match available.iter().position(|&weight| weight == e.weight()) {
+                        Some(index) => {
+                            available.swap_remove(index);
+                            true
+                        }
+                        None => false,
+                    }
+                })
+            })
+        })
+    }
+
     pub fn unused_v_key_from(&self, origin: i32) -> i32 {
         let mut key = origin;
         while self.vertice_exists(key) {
@@ -263,6 +709,21 @@ impl DiGraph {
         )
     }
 
+    /// Retorna os sucessores de um vértice junto com o peso de cada aresta: `(destino, peso)`.
+    ///
+    /// Diferente de `get_sucessor`, arestas paralelas aparecem uma vez cada. Ordenado por
+    /// destino e depois pelo id da aresta. `None` se o vértice não existir.
+    pub fn get_successor_edges(&self, vertice_key: i32) -> Option<Vec<(i32, i64)>> {
+        let vertice = self.get_vertice_arc(vertice_key)?;
+        Some(
+            vertice
+                .edges_sorted()
+                .iter()
+                .map(|e| (e.destiny_key(), e.weight() as i64))
+                .collect(),
+        )
+    }
+
     /// Retorna um vetor clonado de todas as arestas de um vértice.
     pub fn edges_of(&self, vertice_key: i32) -> Option<Vec<Edge>> {
         let vertice = self.get_vertice_arc(vertice_key)?;
@@ -282,6 +743,28 @@ impl DiGraph {
         Some(list)
     }
 
+    /// Predecessores de todos os vértices de uma vez, em uma única passada pelas arestas.
+    ///
+    /// Mesmo conteúdo de chamar `predecessor` para cada vértice (uma entrada por aresta, então
+    /// arestas paralelas repetem a origem), mas em O(V + E) no total em vez de O(V·(V + E)).
+    /// Todo vértice tem uma entrada; cada lista vem em ordem crescente.
+    pub fn all_predecessors(&self) -> HashMap<i32, Vec<i32>> {
+        let mut predecessors: HashMap<i32, Vec<i32>> =
+            self.vertices.keys().map(|&key| (key, Vec::new())).collect();
+        for vertice in self.vertices.values() {
+            for edge in vertice.edges_vec_ref() {
+                predecessors
+                    .get_mut(&edge.destiny_key())
+                    .unwrap()
+                    .push(edge.origin_key());
+            }
+        }
+        for list in predecessors.values_mut() {
+            list.sort();
+        }
+        predecessors
+    }
+
     /// Retorna arestas predecessoras de um vertice
     pub fn predecessor_edges(&self, vertice_key: i32) -> Option<Vec<Edge>> {
         let mut list: Vec<Edge> = Vec::new();
@@ -295,10 +778,36 @@ impl DiGraph {
         Some(list)
     }
 
+    /// Retorna as chaves dos vizinhos de um vértice ignorando a direção das arestas.
+    ///
+    /// Une os sucessores e os predecessores, sem repetições.
+    pub fn undirected_neighbors(&self, vertice_key: i32) -> Option<HashSet<i32>> {
+        let vertice = self.get_vertice_arc(vertice_key)?;
+        let mut neighbors: HashSet<i32> = vertice
+            .edges_hashmap()
+            .keys()
+            .map(|&(_, dest)| dest)
+            .collect();
+        for e in vertice.back_edges_hashmap() {
+            neighbors.insert(e.origin_key());
+        }
+        Some(neighbors)
+    }
+
+    /// Retorna a vizinhança não direcionada de um vértice (sucessores e predecessores),
+    /// sem repetições e em ordem crescente.
+    ///
+    /// Um laço faz o vértice aparecer na própria vizinhança. `None` se o vértice não existir.
+    pub fn neighbors(&self, vertice_key: i32) -> Option<Vec<i32>> {
+        let mut neighbors: Vec<i32> = self.undirected_neighbors(vertice_key)?.into_iter().collect();
+        neighbors.sort();
+        Some(neighbors)
+    }
+
     /// Executa uma busca em profundidade a partir de uma chave de vértice.
     pub fn dfs_search(&self, mut search_key: i32) -> DfsStruct {
         let mut dfs_data = DfsStruct::new(self);
-        let key_array = self.get_vertice_key_array();
+        let key_array = self.vertices_sorted();
         while search_key != -1 {
             self.DeepFirstSearch(search_key, &mut dfs_data);
             search_key = dfs_data.get_unexplored_vertice(&key_array);
@@ -306,16 +815,84 @@ impl DiGraph {
         dfs_data
     }
 
+    /// Executa uma busca em profundidade explorando as arestas de cada vértice na ordem `order`.
+    ///
+    /// Igual a `dfs_search`, mas permite controlar o desempate entre vizinhos.
+    pub fn dfs_search_with(&self, mut search_key: i32, order: NeighborOrder) -> DfsStruct {
+        let mut dfs_data = DfsStruct::new(self);
+        let key_array = self.vertices_sorted();
+        while search_key != -1 {
+            self.deep_first_search_ordered(search_key, &mut dfs_data, &order);
+            search_key = dfs_data.get_unexplored_vertice(&key_array);
+        }
+        dfs_data
+    }
+
+    /// Cria o subgrafo induzido pelos vértices `keys`.
+    ///
+    /// Mantém apenas as arestas com as duas extremidades em `keys` (com mesmo id e peso).
+    /// Chaves inexistentes são ignoradas.
+    pub fn induced_subgraph(&self, keys: &[i32]) -> DiGraph {
+        let key_set: HashSet<i32> = keys.iter().copied().collect();
+        let mut subgraph = DiGraph::new_sized(key_set.len() as u32);
+        for &v in keys.iter().filter(|&&v| self.vertice_exists(v)) {
+            subgraph.add_vertice(v);
+        }
+        for &v in key_set.iter() {
+            let Some(vertice) = self.get_vertice_arc(v) else {
+                continue;
+            };
+            for e in vertice.edges_sorted() {
+                if key_set.contains(&e.destiny_key()) {
+                    subgraph.add_edge(e);
+                }
+            }
+        }
+        subgraph
+    }
+
+    /// Cria um novo grafo com apenas as arestas de peso `<= max_weight`.
+    ///
+    /// Todos os vértices são mantidos, mesmo os que ficarem isolados.
+    /// As arestas mantidas conservam id e peso.
+    pub fn filter_edges_by_weight(&self, max_weight: i64) -> DiGraph {
+        self.filter_edges(|e| e.weight() as i64 <= max_weight)
+    }
+
+    /// Cria um novo grafo com apenas as arestas de peso `>= min_weight`.
+    ///
+    /// Todos os vértices são mantidos, mesmo os que ficarem isolados.
+    /// As arestas mantidas conservam id e peso.
+    pub fn filter_edges_by_min_weight(&self, min_weight: i64) -> DiGraph {
+        self.filter_edges(|e| e.weight() as i64 >= min_weight)
+    }
+
+    fn filter_edges<F>(&self, keep: F) -> DiGraph
+    where
+        F: Fn(&Edge) -> bool,
+    {
+        let mut filtered = DiGraph::new_sized(self.vertices_len);
+        for v in self.vertices_sorted() {
+            filtered.add_vertice(v);
+        }
+        for e in self.edges_sorted().into_iter().filter(|e| keep(e)) {
+            filtered.add_edge(e);
+        }
+        filtered
+    }
+
     /// Cria um novo grafo com todas as arestas transpostas.
     ///
     /// Na prática, apenas inverte as arestas direcionadas.
+    /// Vértices isolados são mantidos, e cada aresta transposta conserva id e peso.
     pub fn transpose(&self) -> DiGraph {
         let mut t_graph = DiGraph::new_sized(self.vertices_len);
         let vertices = self.get_vertice_key_array();
         for vertice in vertices {
+            t_graph.add_vertice(vertice);
             if let Some(edges) = self.edges_of(vertice) {
                 for edge in edges {
-                    t_graph.add_edge(Edge::new(edge.destiny_key(), edge.origin_key()));
+                    t_graph.add_edge(edge.rewired(edge.destiny_key(), edge.origin_key()));
                 }
             }
         }
@@ -499,6 +1076,161 @@ impl DiGraph {
     }
 }
 
+// Atributos de arestas
+#[allow(unused)]
+impl DiGraph {
+    /// Define o atributo `key` da aresta com id `edge_id`.
+    ///
+    /// Os atributos são apagados quando a aresta é removida do grafo.
+    pub fn set_edge_attr(&mut self, edge_id: usize, key: &str, value: EdgeAttrValue) {
+        self.version += 1;
+        self.edge_attrs
+            .entry(edge_id)
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+
+    /// Obtém o atributo `key` da aresta com id `edge_id`, se definido.
+    pub fn get_edge_attr(&self, edge_id: usize, key: &str) -> Option<&EdgeAttrValue> {
+        self.edge_attrs.get(&edge_id)?.get(key)
+    }
+
+    /// Obtém todos os atributos da aresta com id `edge_id`.
+    pub fn edge_attrs(&self, edge_id: usize) -> Option<&EdgeAttrs> {
+        self.edge_attrs.get(&edge_id)
+    }
+}
+
+// Versão
+#[allow(unused)]
+impl DiGraph {
+    /// Contador de modificações do grafo.
+    ///
+    /// É incrementado a cada mutação (vértices, arestas, pesos ou atributos), então dois valores
+    /// iguais garantem que o grafo não mudou entre eles. Usado por `tools::cached::Cached`
+    /// para invalidar métricas memorizadas.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Identificador único desta instância do grafo no processo.
+    ///
+    /// Cada `DiGraph` criado (inclusive por `clone`) recebe um valor novo, então grafos
+    /// diferentes podem ter a mesma `version` mas nunca o mesmo `instance_id`.
+    pub fn instance_id(&self) -> u64 {
+        self.instance
+    }
+}
+
+// Consistência
+#[allow(unused)]
+impl DiGraph {
+    /// Verifica, em builds de debug, se a estrutura interna está consistente:
+    /// - `vertices_len` e `edges_len` batem com o que está guardado nos vértices
+    /// - toda aresta de retorno (`back_edge`) tem a aresta sucessora correspondente (mesmo id)
+    ///
+    /// Entra em pânico na primeira divergência. Custa O(V + E), por isso as chamadas internas
+    /// ficam atrás de `#[cfg(debug_assertions)]`: ao fim de cada método que altera a estrutura.
+    /// `add_vertice`/`add_edge` usam a versão barata `debug_assert_insertion`, para que construir
+    /// um grafo aresta por aresta continue linear. Os construtores em lote (`from_edges`,
+    /// `from_edge_list`, `from_weighted_edges`, `from_file`, `from_bytes`) verificam uma única
+    /// vez no final.
+    pub fn debug_assert_consistent(&self) {
+        debug_assert_eq!(
+            self.vertices_len as usize,
+            self.vertices.len(),
+            "vertices_len diverge do número de vértices"
+        );
+        let edges: usize = self.vertices.values().map(|v| v.out_degree()).sum();
+        let back_edges: usize = self.vertices.values().map(|v| v.in_degree()).sum();
+        debug_assert_eq!(self.edges_len, edges, "edges_len diverge do número de arestas");
+        debug_assert_eq!(edges, back_edges, "arestas e arestas de retorno em número diferente");
+
+        for vertice in self.vertices.values() {
+            for back in vertice.back_edges_hashmap() {
+                let (v, w) = back.v_w();
+                let found = self
+                    .vertices
+                    .get(&v)
+                    .and_then(|origin| origin.get_edges_to(w))
+                    .is_some_and(|edges| edges.iter().any(|e| e.id() == back.id()));
+                debug_assert!(found, "aresta de retorno {:?} sem aresta sucessora", back);
+            }
+        }
+    }
+
+    /// Verificação de `add_vertice`/`add_edge`, sem varrer o grafo: o contador de vértices e,
+    /// se `pair` for `Some((v, w))`, se as arestas `v -> w` e as de retorno em `w` batem.
+    fn debug_assert_insertion(&self, pair: Option<(i32, i32)>) {
+        debug_assert_eq!(
+            self.vertices_len as usize,
+            self.vertices.len(),
+            "vertices_len diverge do número de vértices"
+        );
+        if let Some((v, w)) = pair {
+            let edges = self.vertices[&v].get_edges_to(w).map_or(0, |list| list.len());
+            let back_edges = self.vertices[&w]
+                .get_back_edges_from(v)
+                .map_or(0, |list| list.len());
+            debug_assert_eq!(
+                edges, back_edges,
+                "arestas {} -> {} e arestas de retorno em número diferente",
+                v, w
+            );
+        }
+    }
+
+    /// Sobrescreve os contadores sem tocar nos vértices, para testes que precisam de um
+    /// grafo inconsistente.
+    #[cfg(test)]
+    pub(crate) fn set_counts_for_test(&mut self, vertices_len: u32, edges_len: usize) {
+        self.vertices_len = vertices_len;
+        self.edges_len = edges_len;
+    }
+}
+
+/// Erro das consultas por chave (`try_*`): o vértice `key` não existe no grafo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexNotFound {
+    pub key: i32,
+}
+
+impl fmt::Display for VertexNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "vértice {} não existe no grafo", self.key)
+    }
+}
+
+impl std::error::Error for VertexNotFound {}
+
+// Consultas com erro
+#[allow(unused)]
+impl DiGraph {
+    /// Como `get_sucessor`, mas com `VertexNotFound` (com a chave) se o vértice não existir.
+    pub fn try_successors(&self, key: i32) -> Result<Vec<i32>, VertexNotFound> {
+        self.get_sucessor(key).ok_or(VertexNotFound { key })
+    }
+
+    /// Como `get_successor_edges`, mas com `VertexNotFound` se o vértice não existir.
+    pub fn try_successor_edges(&self, key: i32) -> Result<Vec<(i32, i64)>, VertexNotFound> {
+        self.get_successor_edges(key).ok_or(VertexNotFound { key })
+    }
+
+    /// Como `predecessor`, mas com `VertexNotFound` se o vértice não existir
+    /// (`predecessor` devolve uma lista vazia nesse caso).
+    pub fn try_predecessors(&self, key: i32) -> Result<Vec<i32>, VertexNotFound> {
+        if !self.vertice_exists(key) {
+            return Err(VertexNotFound { key });
+        }
+        Ok(self.predecessor(key).unwrap_or_default())
+    }
+
+    /// Como `edges_of`, mas com `VertexNotFound` se o vértice não existir.
+    pub fn try_edges_of(&self, key: i32) -> Result<Vec<Edge>, VertexNotFound> {
+        self.edges_of(key).ok_or(VertexNotFound { key })
+    }
+}
+
 // Iteradores
 impl DiGraph {
     /// Retorna um iterador sobre os vértices do grafo.
@@ -507,17 +1239,30 @@ impl DiGraph {
     }
 }
 
+/// Os vértices (e suas arestas) são armazenados por valor,
+/// então o clone é uma cópia profunda e independente do grafo original.
 impl Clone for DiGraph {
     fn clone(&self) -> Self {
         Self {
             vertices_len: self.vertices_len.clone(),
             edges_len: self.edges_len.clone(),
             vertices: self.vertices.clone(),
+            edge_attrs: self.edge_attrs.clone(),
+            version: self.version,
+            instance: next_instance(),
         }
     }
 }
 
+#[allow(unused)]
 impl DiGraph {
+    /// Cria uma cópia totalmente independente do grafo.
+    ///
+    /// Alterações na cópia (ex: contrações destrutivas) não afetam o grafo original.
+    pub fn deep_clone(&self) -> DiGraph {
+        self.clone()
+    }
+
     /// Encontra um par de vértices (base, antibase) tal que:
     /// - `base` não tem predecessores.
     /// - `base` alcança `antibase`.
@@ -572,3 +1317,946 @@ impl DiGraph{
         fs::write(file_path, csv).expect("Erro ao escrever arquivo");
     }
 }
+
+// Exibição
+#[allow(unused)]
+impl DiGraph {
+    /// Lista de adjacência em texto, para depuração.
+    ///
+    /// Uma linha por vértice, em ordem crescente: `1 -> [2, 3[5]]`. Os sucessores vêm
+    /// ordenados, arestas paralelas aparecem repetidas e pesos diferentes de 1 são
+    /// mostrados entre colchetes após o destino.
+    pub fn pretty_print(&self) -> String {
+        let mut text = String::new();
+        for v in self.vertices_sorted() {
+            let successors: Vec<String> = self
+                .get_vertice_arc(v)
+                .unwrap()
+                .edges_sorted()
+                .iter()
+                .map(|e| match e.weight() {
+                    1 => e.destiny_key().to_string(),
+                    w => format!("{}[{}]", e.destiny_key(), w),
+                })
+                .collect();
+            text.push_str(&format!("{} -> [{}]\n", v, successors.join(", ")));
+        }
+        text
+    }
+}
+
+impl std::fmt::Display for DiGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pretty_print())
+    }
+}
+
+// to json
+#[allow(unused)]
+impl DiGraph {
+    /// Exporta a lista de adjacência como JSON, sem depender de serde.
+    ///
+    /// Formato: `{"1":[2,3],"2":[3]}`, com cada chave de vértice (como string) apontando para
+    /// as chaves dos seus sucessores. Vértices vêm em ordem crescente, e arestas paralelas
+    /// aparecem repetidas (uma entrada por aresta, ordenadas por destino).
+    pub fn to_json_adjacency(&self) -> String {
+        let entries: Vec<String> = self
+            .vertices_sorted()
+            .into_iter()
+            .map(|v| {
+                let successors: Vec<String> = self
+                    .get_vertice_arc(v)
+                    .unwrap()
+                    .edges_sorted()
+                    .iter()
+                    .map(|e| e.destiny_key().to_string())
+                    .collect();
+                format!("\"{}\":[{}]", v, successors.join(","))
+            })
+            .collect();
+        format!("{{{}}}", entries.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_graph_runs_shortest_paths_concurrently() {
+        use crate::graph_lib::minPath::bellman::find_shortest_path;
+        use std::{sync::Arc, thread};
+
+        let mut edges = Vec::new();
+        for v in 0..40 {
+            edges.push((v, (v * 7 + 3) % 40, v % 5 + 1));
+            edges.push((v, (v + 1) % 40, 9));
+        }
+        let graph = Arc::new(DiGraph::from_weighted_edges(&edges));
+        let sources: Vec<i32> = (0..40).step_by(5).collect();
+        let expected: Vec<_> = sources
+            .iter()
+            .map(|&s| find_shortest_path(&graph, s).pot().clone())
+            .collect();
+
+        let handles: Vec<_> = sources
+            .iter()
+            .map(|&s| {
+                let graph = Arc::clone(&graph);
+                thread::spawn(move || find_shortest_path(&graph, s).pot().clone())
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn edges_sorted_is_stable_across_runs() {
+        // Arestas paralelas e vértices com muitas saídas, para o HashMap embaralhar a ordem
+        let mut g = DiGraph::new();
+        for v in 0..30 {
+            for w in [(v * 11) % 30, (v + 3) % 30, (v * 11) % 30] {
+                g.add_edge(Edge::new_weighted(v, w, v + w));
+            }
+        }
+        let first = g.edges_sorted();
+        for _ in 0..20 {
+            assert_eq!(g.edges_sorted(), first);
+        }
+        let keys: Vec<_> = first
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.id()))
+            .collect();
+        let mut expected = keys.clone();
+        expected.sort();
+        assert_eq!(keys, expected);
+
+        // Uma cópia produz a mesma sequência de arestas
+        assert_eq!(g.clone().edges_sorted(), first);
+    }
+
+    #[test]
+    fn deep_clone_is_independent_of_the_original() {
+        let mut original = DiGraph::from_weighted_edges(&[(1, 2, 3), (2, 3, 4), (3, 1, 5)]);
+        let id = original.edges_sorted()[0].id();
+        original.set_edge_attr(id, "label", EdgeAttrValue::Text("a".to_string()));
+        let before = original.edges_sorted();
+
+        let mut copy = original.deep_clone();
+        copy.remove_vertice(2);
+        copy.add_edge(Edge::new(3, 9));
+        copy.set_edge_attr(id, "label", EdgeAttrValue::Text("b".to_string()));
+
+        assert_eq!(original.vertices_sorted(), vec![1, 2, 3]);
+        assert_eq!(original.edges_sorted(), before);
+        assert_eq!(
+            original.get_edge_attr(id, "label"),
+            Some(&EdgeAttrValue::Text("a".to_string()))
+        );
+        assert_eq!(copy.vertices_sorted(), vec![1, 3, 9]);
+        assert_eq!(copy.edges_length(), 2);
+    }
+
+    #[test]
+    fn vertices_sorted_is_stable_and_restarts_dfs_in_order() {
+        let mut g = DiGraph::new();
+        for key in [42, -3, 17, 8, 100, 0, 5] {
+            g.add_vertice(key);
+        }
+        g.add_edge(Edge::new(8, 17));
+        let first = g.vertices_sorted();
+        assert_eq!(first, vec![-3, 0, 5, 8, 17, 42, 100]);
+        for _ in 0..20 {
+            assert_eq!(g.vertices_sorted(), first);
+        }
+
+        // Cada componente é reiniciado pelo menor vértice ainda não visitado
+        let dfs = g.dfs_search(42);
+        let mut roots = dfs.get_roots();
+        assert_eq!(roots.len(), 6);
+        roots.sort_by_key(|&r| dfs.discovery_time(r));
+        assert_eq!(roots, vec![42, -3, 0, 5, 8, 100]);
+    }
+
+    #[test]
+    fn is_simple_detects_parallel_edges_and_loops() {
+        let simple = DiGraph::from_edge_list(&[(1, 2), (2, 1), (2, 3)]);
+        assert!(simple.is_simple());
+
+        let mut parallel = simple.clone();
+        parallel.add_edge(Edge::new(2, 3));
+        assert!(!parallel.is_simple());
+
+        let mut with_loop = simple.clone();
+        with_loop.add_edge(Edge::new(3, 3));
+        assert!(!with_loop.is_simple());
+    }
+
+    #[test]
+    fn constructors_from_slices_build_the_adjacency() {
+        let g = DiGraph::from_edge_list(&[(1, 2), (1, 3), (3, 1), (4, 4), (1, 2)]);
+        assert_eq!(g.vertices_sorted(), vec![1, 2, 3, 4]);
+        assert_eq!(g.edges_length(), 5);
+        let mut successors = g.get_sucessor(1).unwrap();
+        successors.sort();
+        successors.dedup();
+        assert_eq!(successors, vec![2, 3]);
+        assert_eq!(g.get_edges(1, 2).unwrap().len(), 2);
+        // O destino também conhece a aresta (predecessores)
+        let predecessors: Vec<i32> = g
+            .predecessor_edges(1)
+            .unwrap()
+            .iter()
+            .map(|e| e.origin_key())
+            .collect();
+        assert_eq!(predecessors, vec![3]);
+        assert!(g.edges_sorted().iter().all(|e| e.weight() == 1));
+
+        let weighted = DiGraph::from_weighted_edges(&[(5, 6, -2), (6, 7, 9)]);
+        let triples: Vec<_> = weighted
+            .edges_sorted()
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+        assert_eq!(triples, vec![(5, 6, -2), (6, 7, 9)]);
+        assert_eq!(weighted.vertices_length(), 3);
+    }
+
+    #[test]
+    fn merge_vertices_rewires_edges_into_keep() {
+        let mut g = DiGraph::from_weighted_edges(&[
+            (1, 2, 3),
+            (3, 2, 4),
+            (2, 4, 5),
+            (2, 4, 6),
+            (1, 4, 1),
+            (2, 2, 7),
+            (4, 1, 1),
+        ]);
+        let parallel_ids: Vec<usize> = g.get_edges(2, 4).unwrap().iter().map(|e| e.id()).collect();
+
+        assert!(g.merge_vertices(1, 2));
+        assert_eq!(g.vertices_sorted(), vec![1, 3, 4]);
+        let mut triples: Vec<_> = g
+            .edges_sorted()
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+        triples.sort();
+        // 1 -> 2 e o laço em 2 seriam laços em 1 e são descartados
+        assert_eq!(
+            triples,
+            vec![(1, 4, 1), (1, 4, 5), (1, 4, 6), (3, 1, 4), (4, 1, 1)]
+        );
+        assert_eq!(g.edges_length(), 5);
+        let mut kept_ids: Vec<usize> = g.get_edges(1, 4).unwrap().iter().map(|e| e.id()).collect();
+        kept_ids.retain(|id| parallel_ids.contains(id));
+        assert_eq!(kept_ids.len(), 2);
+
+        assert!(!g.merge_vertices(1, 1));
+        assert!(!g.merge_vertices(1, 2));
+        assert!(!g.merge_vertices(9, 3));
+    }
+
+    #[test]
+    fn lone_vertex_is_isolated() {
+        let mut g = DiGraph::from_edge_list(&[(1, 2), (3, 3)]);
+        g.add_vertice(7);
+        assert_eq!(g.is_isolated(7), Some(true));
+        assert_eq!(g.is_isolated(2), Some(false));
+        // Um laço conta como aresta de entrada e de saída
+        assert_eq!(g.is_isolated(3), Some(false));
+        assert_eq!(g.is_isolated(9), None);
+        assert_eq!(g.isolated_vertices(), vec![7]);
+
+        g.remove_vertice(2);
+        assert_eq!(g.isolated_vertices(), vec![1, 7]);
+    }
+
+    #[test]
+    fn is_subgraph_of_checks_vertices_edges_and_multiplicity() {
+        let big = DiGraph::from_weighted_edges(&[(1, 2, 5), (1, 2, 5), (2, 3, 1), (3, 1, 2)]);
+
+        let mut sub = DiGraph::from_weighted_edges(&[(1, 2, 5), (3, 1, 2)]);
+        assert!(sub.is_subgraph_of(&big));
+        assert!(big.is_subgraph_of(&big));
+
+        // Aresta ausente em `big`
+        let missing = DiGraph::from_weighted_edges(&[(2, 1, 5)]);
+        assert!(!missing.is_subgraph_of(&big));
+        // Mesmo par com peso diferente
+        let other_weight = DiGraph::from_weighted_edges(&[(2, 3, 4)]);
+        assert!(!other_weight.is_subgraph_of(&big));
+
+        // Duas paralelas cabem, três não
+        sub.add_edge(Edge::new_weighted(1, 2, 5));
+        assert!(sub.is_subgraph_of(&big));
+        sub.add_edge(Edge::new_weighted(1, 2, 5));
+        assert!(!sub.is_subgraph_of(&big));
+
+        let mut extra_vertex = DiGraph::new();
+        extra_vertex.add_vertice(9);
+        assert!(!extra_vertex.is_subgraph_of(&big));
+    }
+
+    #[test]
+    fn edge_attributes_are_set_read_and_dropped_with_the_edge() {
+        let mut g = DiGraph::from_weighted_edges(&[(1, 2, 3), (2, 3, 4), (3, 1, 5)]);
+        let edge = g.get_edges(1, 2).unwrap()[0].clone();
+        let other = g.get_edges(2, 3).unwrap()[0].id();
+
+        g.set_edge_attr(edge.id(), "capacity", EdgeAttrValue::Int(10));
+        g.set_edge_attr(edge.id(), "cost", EdgeAttrValue::Float(0.5));
+        g.set_edge_attr(edge.id(), "capacity", EdgeAttrValue::Int(12));
+        g.set_edge_attr(other, "label", EdgeAttrValue::Text("ponte".to_string()));
+
+        assert_eq!(
+            g.get_edge_attr(edge.id(), "capacity"),
+            Some(&EdgeAttrValue::Int(12))
+        );
+        assert_eq!(
+            g.get_edge_attr(edge.id(), "cost"),
+            Some(&EdgeAttrValue::Float(0.5))
+        );
+        assert_eq!(g.get_edge_attr(edge.id(), "label"), None);
+        assert_eq!(g.edge_attrs(edge.id()).unwrap().len(), 2);
+
+        g.remove_edge(edge.clone());
+        assert_eq!(g.edge_attrs(edge.id()), None);
+        g.remove_vertice(3);
+        assert_eq!(g.get_edge_attr(other, "label"), None);
+    }
+
+    #[test]
+    fn add_or_update_edge_combines_instead_of_adding_parallels() {
+        let mut g = DiGraph::new();
+        g.add_or_update_edge(1, 2, 5, ParallelCombine::Min);
+        g.add_or_update_edge(1, 2, 3, ParallelCombine::Min);
+        let edges = g.get_edges(1, 2).unwrap();
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].weight(), 3);
+        assert_eq!(g.edges_length(), 1);
+        // O predecessor enxerga o mesmo peso
+        assert_eq!(g.predecessor_edges(2).unwrap()[0].weight(), 3);
+
+        let id = edges[0].id();
+        for (policy, weight, expected) in [
+            (ParallelCombine::Max, 4, 4),
+            (ParallelCombine::Sum, 6, 10),
+            (ParallelCombine::Replace, 1, 1),
+        ] {
+            g.add_or_update_edge(1, 2, weight, policy);
+            let edges = g.get_edges(1, 2).unwrap();
+            assert_eq!(
+                (edges.len(), edges[0].id(), edges[0].weight()),
+                (1, id, expected)
+            );
+        }
+
+        // O sentido oposto é outra aresta
+        g.add_or_update_edge(2, 1, 7, ParallelCombine::Min);
+        assert_eq!(g.edges_length(), 2);
+    }
+
+    #[test]
+    fn reverse_in_place_swaps_successors_and_predecessors() {
+        let mut g = DiGraph::from_weighted_edges(&[(1, 2, 4), (1, 3, 5), (3, 2, 6), (2, 2, 1)]);
+        g.add_vertice(9);
+        let before: Vec<_> = g
+            .edges_sorted()
+            .iter()
+            .map(|e| (e.id(), e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+
+        g.reverse_in_place();
+        assert_eq!(g.vertices_length(), 4);
+        assert_eq!(g.edges_length(), 4);
+        let mut after: Vec<_> = g
+            .edges_sorted()
+            .iter()
+            .map(|e| (e.id(), e.destiny_key(), e.origin_key(), e.weight()))
+            .collect();
+        after.sort();
+        let mut by_id = before.clone();
+        by_id.sort();
+        assert_eq!(after, by_id);
+
+        // Sucessores viraram predecessores
+        let mut successors = g.get_sucessor(2).unwrap();
+        successors.sort();
+        assert_eq!(successors, vec![1, 2, 3]);
+        assert!(g.get_sucessor(1).unwrap().is_empty());
+        let mut predecessors: Vec<i32> = g
+            .predecessor_edges(1)
+            .unwrap()
+            .iter()
+            .map(|e| e.origin_key())
+            .collect();
+        predecessors.sort();
+        assert_eq!(predecessors, vec![2, 3]);
+
+        // Inverter duas vezes volta ao original
+        g.reverse_in_place();
+        let again: Vec<_> = g
+            .edges_sorted()
+            .iter()
+            .map(|e| (e.id(), e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+        assert_eq!(again, before);
+    }
+
+    #[test]
+    fn extreme_weight_edges() {
+        let g = DiGraph::from_weighted_edges(&[(1, 2, 7), (2, 3, -4), (3, 1, 12), (1, 3, 0)]);
+        assert_eq!(g.max_weight_edge().unwrap().v_w(), (3, 1));
+        assert_eq!(g.min_weight_edge().unwrap().v_w(), (2, 3));
+        assert_eq!(DiGraph::new().max_weight_edge(), None);
+        assert_eq!(DiGraph::from_edge_list(&[]).min_weight_edge(), None);
+
+        // Empate: fica a aresta criada primeiro (menor id)
+        let tie = DiGraph::from_weighted_edges(&[(5, 6, 3), (1, 2, 3)]);
+        assert_eq!(tie.max_weight_edge().unwrap().v_w(), (5, 6));
+        assert_eq!(tie.min_weight_edge().unwrap().v_w(), (5, 6));
+    }
+
+    #[test]
+    fn filter_edges_by_weight_keeps_vertices() {
+        let g = DiGraph::from_weighted_edges(&[(1, 2, 1), (2, 3, 5), (3, 4, 9), (4, 1, 5)]);
+        let triples = |g: &DiGraph| -> Vec<(i32, i32, i32)> {
+            g.edges_sorted()
+                .iter()
+                .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+                .collect()
+        };
+
+        let light = g.filter_edges_by_weight(5);
+        assert_eq!(triples(&light), vec![(1, 2, 1), (2, 3, 5), (4, 1, 5)]);
+        assert_eq!(light.vertices_sorted(), vec![1, 2, 3, 4]);
+
+        let heavy = g.filter_edges_by_min_weight(6);
+        assert_eq!(triples(&heavy), vec![(3, 4, 9)]);
+        assert_eq!(heavy.isolated_vertices(), vec![1, 2]);
+        assert_eq!(heavy.edges_length(), 1);
+
+        // Os ids das arestas mantidas são os originais
+        assert_eq!(
+            heavy.edges_sorted()[0].id(),
+            g.get_edges(3, 4).unwrap()[0].id()
+        );
+    }
+
+    #[test]
+    fn neighbors_merges_successors_and_predecessors() {
+        // 2 tem 3 como sucessor e predecessor, além de uma aresta paralela para 4
+        let g = DiGraph::from_edge_list(&[(1, 2), (2, 3), (3, 2), (2, 4), (2, 4), (5, 5)]);
+        assert_eq!(g.neighbors(2), Some(vec![1, 3, 4]));
+        assert_eq!(g.neighbors(1), Some(vec![2]));
+        assert_eq!(g.neighbors(5), Some(vec![5]));
+        assert_eq!(g.neighbors(9), None);
+    }
+
+    /// Lê `{"1":[2,3],"2":[]}` de forma estrita; `None` se não for JSON nesse formato
+    fn parse_json_adjacency(json: &str) -> Option<Vec<(i32, Vec<i32>)>> {
+        let body = json.strip_prefix('{')?.strip_suffix('}')?;
+        let mut entries = Vec::new();
+        let mut rest = body;
+        while !rest.is_empty() {
+            let (key, tail) = rest.strip_prefix('"')?.split_once("\":[")?;
+            let (list, tail) = tail.split_once(']')?;
+            let values = if list.is_empty() {
+                Vec::new()
+            } else {
+                list.split(',')
+                    .map(|v| v.parse().ok())
+                    .collect::<Option<Vec<i32>>>()?
+            };
+            entries.push((key.parse().ok()?, values));
+            rest = match tail.strip_prefix(',') {
+                Some(next) if !next.is_empty() => next,
+                Some(_) => return None,
+                None if tail.is_empty() => tail,
+                None => return None,
+            };
+        }
+        Some(entries)
+    }
+
+    #[test]
+    fn json_adjacency_round_trips() {
+        let mut g = DiGraph::from_edge_list(&[(2, 3), (1, 3), (1, 2), (1, 3), (-4, 1)]);
+        g.add_vertice(10);
+        let json = g.to_json_adjacency();
+        assert_eq!(json, r#"{"-4":[1],"1":[2,3,3],"2":[3],"3":[],"10":[]}"#);
+
+        let parsed = parse_json_adjacency(&json).unwrap();
+        let keys: Vec<i32> = parsed.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, g.vertices_sorted());
+        // Uma entrada por aresta, então paralelas aparecem repetidas
+        for (key, successors) in parsed {
+            let expected: Vec<i32> = g
+                .get_vertice_arc(key)
+                .unwrap()
+                .edges_sorted()
+                .iter()
+                .map(|e| e.destiny_key())
+                .collect();
+            assert_eq!(successors, expected);
+        }
+        assert_eq!(
+            parse_json_adjacency(&DiGraph::new().to_json_adjacency()),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn retain_edges_drops_even_weights() {
+        let mut g =
+            DiGraph::from_weighted_edges(&[(1, 2, 2), (1, 2, 3), (2, 3, 4), (3, 1, 5), (3, 3, 6)]);
+        let id = g.get_edges(2, 3).unwrap()[0].id();
+        g.set_edge_attr(id, "label", EdgeAttrValue::Int(1));
+
+        g.retain_edges(|e| e.weight() % 2 != 0);
+        let triples: Vec<_> = g
+            .edges_sorted()
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+        assert_eq!(triples, vec![(1, 2, 3), (3, 1, 5)]);
+        assert_eq!(g.edges_length(), 2);
+        assert_eq!(g.vertices_sorted(), vec![1, 2, 3]);
+        assert!(g.predecessor_edges(3).unwrap().is_empty());
+        assert_eq!(g.edge_attrs(id), None);
+    }
+
+    #[test]
+    fn remove_vertice_returning_gives_back_incident_edges() {
+        let mut g =
+            DiGraph::from_weighted_edges(&[(0, 1, 5), (1, 2, 7), (2, 1, 3), (1, 1, 9), (2, 0, 1)]);
+        let triple = |e: &Edge| (e.origin_key(), e.destiny_key(), e.weight());
+        let mut expected_out: Vec<_> = g
+            .get_vertice_arc(1)
+            .unwrap()
+            .edges_sorted()
+            .iter()
+            .map(triple)
+            .collect();
+        expected_out.sort();
+
+        let (out, back) = g.remove_vertice_returning(1).unwrap();
+        let mut out: Vec<_> = out.iter().map(triple).collect();
+        let mut back: Vec<_> = back.iter().map(triple).collect();
+        out.sort();
+        back.sort();
+        assert_eq!(out, expected_out);
+        assert_eq!(out, vec![(1, 1, 9), (1, 2, 7)]);
+        // O laço aparece nas duas listas
+        assert_eq!(back, vec![(0, 1, 5), (1, 1, 9), (2, 1, 3)]);
+
+        assert!(!g.vertice_exists(1));
+        assert_eq!(g.edges_length(), 1);
+        assert_eq!(g.remove_vertice_returning(1), None);
+    }
+
+    #[test]
+    fn scale_weights_scales_dijkstra_distances() {
+        use crate::graph_lib::minPath::dijkstra::Dijkstra;
+        use crate::tools::inifinity::Infinity::Number;
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(400);
+        for _ in 0..50 {
+            let mut edges = Vec::new();
+            for _ in 0..20 {
+                edges.push((
+                    rng.gen_range(0..8),
+                    rng.gen_range(0..8),
+                    rng.gen_range(0..10),
+                ));
+            }
+            let mut g = DiGraph::from_weighted_edges(&edges);
+            let before = Dijkstra::shortest_path(&g, edges[0].0).dist().clone();
+            g.scale_weights(3);
+            g.debug_assert_consistent();
+            let after = Dijkstra::shortest_path(&g, edges[0].0).dist().clone();
+            for (v, d) in before {
+                let expected = match d {
+                    Number(d) => Number(d * 3),
+                    other => other,
+                };
+                assert_eq!(after[&v], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn offset_and_scale_saturate() {
+        let mut g = DiGraph::from_weighted_edges(&[(0, 1, 5), (1, 2, -4), (2, 0, i32::MAX - 1)]);
+        g.offset_weights(2);
+        g.debug_assert_consistent();
+        let weights = |g: &DiGraph| {
+            g.edges_sorted()
+                .iter()
+                .map(|e| e.weight())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(weights(&g), vec![7, -2, i32::MAX]);
+        // As cópias em back_edges acompanham
+        assert_eq!(
+            g.get_vertice_arc(1).unwrap().back_edges_hashmap()[0].weight(),
+            7
+        );
+
+        g.scale_weights(-1);
+        assert_eq!(weights(&g), vec![-7, 2, -i32::MAX]);
+        g.offset_weights(i64::MIN);
+        assert_eq!(weights(&g), vec![i32::MIN; 3]);
+    }
+
+    #[test]
+    fn pretty_print_lists_sorted_successors_with_weights() {
+        let mut g = DiGraph::from_weighted_edges(&[(1, 3, 5), (1, 2, 1), (2, 1, 1), (1, 2, 1)]);
+        g.add_vertice(0);
+        let expected = "0 -> []\n1 -> [2, 2, 3[5]]\n2 -> [1]\n3 -> []\n";
+        assert_eq!(g.pretty_print(), expected);
+        assert_eq!(g.to_string(), expected);
+        assert_eq!(DiGraph::new().pretty_print(), "");
+    }
+
+    #[test]
+    fn parallel_edge_pairs_reports_multiplicity() {
+        let mut g =
+            DiGraph::from_edge_list(&[(1, 2), (1, 2), (1, 2), (2, 1), (3, 3), (3, 3), (2, 3)]);
+        assert_eq!(g.parallel_edge_pairs(), vec![((1, 2), 3), ((3, 3), 2)]);
+
+        // Removendo uma aresta por id a multiplicidade cai
+        let id = g.get_edges(1, 2).unwrap()[0].id();
+        g.remove_edge_with_id(1, 2, id);
+        assert_eq!(g.parallel_edge_pairs(), vec![((1, 2), 2), ((3, 3), 2)]);
+        assert!(DiGraph::from_edge_list(&[(1, 2), (2, 1)])
+            .parallel_edge_pairs()
+            .is_empty());
+    }
+
+    #[test]
+    fn sort_all_edges_orders_parallel_edges_and_keeps_dfs() {
+        // Arestas paralelas inseridas fora da ordem de criação
+        let parallel: Vec<Edge> = (0..4).map(|_| Edge::new(0, 1)).collect();
+        let mut g = DiGraph::from_edge_list(&[(1, 2), (2, 0), (0, 3), (3, 1)]);
+        for e in parallel.iter().rev() {
+            g.add_edge(e.clone());
+        }
+        let times = |g: &DiGraph| {
+            let dfs = g.dfs_search(0);
+            g.vertices_sorted()
+                .into_iter()
+                .map(|v| (dfs.discovery_time(v), dfs.finish_time(v)))
+                .collect::<Vec<_>>()
+        };
+        let before = times(&g);
+        let version = g.version();
+
+        g.sort_all_edges();
+        let ids = |edges: Vec<Edge>| edges.iter().map(|e| e.id()).collect::<Vec<_>>();
+        let expected = ids(parallel);
+        assert_eq!(ids(g.get_edges(0, 1).unwrap()), expected);
+        let back: Vec<Edge> = g.get_vertice_arc(1).unwrap().back_edges_hashmap();
+        let from_zero: Vec<Edge> = back.into_iter().filter(|e| e.origin_key() == 0).collect();
+        assert_eq!(ids(from_zero), expected);
+
+        assert_eq!(times(&g), before);
+        assert_eq!(g.version(), version);
+
+        // Cada vértice guarda a ordem (destino, id) que a busca percorre
+        for v in g.vertices_sorted() {
+            let vertice = g.get_vertice_arc(v).unwrap();
+            let sorted = vertice.sorted_edges_ref().unwrap();
+            assert!(sorted
+                .windows(2)
+                .all(|p| (p[0].destiny_key(), p[0].id()) < (p[1].destiny_key(), p[1].id())));
+            assert_eq!(sorted.len(), vertice.out_degree());
+        }
+
+        // Alterar as arestas de um vértice descarta só a ordem dele
+        g.add_edge(Edge::new(0, 2));
+        assert!(g.get_vertice_arc(0).unwrap().sorted_edges_ref().is_none());
+        assert!(g.get_vertice_arc(1).unwrap().sorted_edges_ref().is_some());
+        g.map_weights(|w| w + 1);
+        assert!(g.get_vertice_arc(1).unwrap().sorted_edges_ref().is_none());
+        let expected = times(&g);
+        g.sort_all_edges();
+        assert_eq!(times(&g), expected);
+        assert!(g
+            .get_vertice_arc(0)
+            .unwrap()
+            .edges_sorted()
+            .iter()
+            .all(|e| e.weight() == 2));
+    }
+
+    #[test]
+    fn negative_weights_and_min_weight() {
+        let mut g = DiGraph::from_weighted_edges(&[(0, 1, 3), (1, 2, 0), (2, 0, 7)]);
+        assert_eq!(g.min_weight(), Some(0));
+        assert!(!g.has_negative_weights());
+
+        g.add_edge(Edge::new_weighted(2, 1, -4));
+        assert_eq!(g.min_weight(), Some(-4));
+        assert!(g.has_negative_weights());
+
+        let mut empty = DiGraph::new();
+        empty.add_vertice(1);
+        assert_eq!(empty.min_weight(), None);
+        assert!(!empty.has_negative_weights());
+    }
+
+    #[test]
+    fn successor_edges_keep_weights_and_parallels() {
+        let mut g =
+            DiGraph::from_weighted_edges(&[(0, 3, 2), (0, 1, 9), (0, 3, 7), (0, 0, 4), (1, 0, 5)]);
+        g.add_vertice(8);
+        assert_eq!(
+            g.get_successor_edges(0),
+            Some(vec![(0, 4), (1, 9), (3, 2), (3, 7)])
+        );
+        assert_eq!(g.get_sucessor(0).unwrap().len(), 3);
+        assert_eq!(g.get_successor_edges(8), Some(vec![]));
+        assert_eq!(g.get_successor_edges(9), None);
+    }
+
+    #[test]
+    fn bulk_loading_matches_edge_by_edge_insertion() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        // Mesmas arestas (mesmos ids) inseridas em lote e uma a uma
+        let mut rng = StdRng::seed_from_u64(435);
+        let edges: Vec<Edge> = (0..1_000)
+            .map(|_| Edge::new(rng.gen_range(0..100), rng.gen_range(0..100)))
+            .collect();
+        let bulk = DiGraph::from_edges(edges.clone());
+        let mut single = DiGraph::new();
+        for e in edges {
+            single.add_edge(e);
+        }
+        bulk.debug_assert_consistent();
+        assert_eq!(bulk.vertices_length(), single.vertices_length());
+        assert_eq!(bulk.edges_length(), single.edges_length());
+        for v in single.vertices_sorted() {
+            let (a, b) = (
+                bulk.get_vertice_arc(v).unwrap(),
+                single.get_vertice_arc(v).unwrap(),
+            );
+            assert_eq!(a.edges_sorted(), b.edges_sorted());
+            let mut back_a = a.back_edges_hashmap();
+            let mut back_b = b.back_edges_hashmap();
+            back_a.sort_by_key(|e| e.id());
+            back_b.sort_by_key(|e| e.id());
+            assert_eq!(back_a, back_b);
+        }
+    }
+
+    #[test]
+    fn from_file_loads_a_large_generated_file() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(4350);
+        let pairs: Vec<(i32, i32)> = (0..200_000)
+            .map(|_| (rng.gen_range(0..5_000), rng.gen_range(0..5_000)))
+            .collect();
+        let path = std::env::temp_dir().join(format!("graphos_bulk_{}.txt", std::process::id()));
+        let mut text = format!("5000 {}\n", pairs.len());
+        for (v, w) in pairs.iter() {
+            text.push_str(&format!("{} {}\n", v, w));
+        }
+        fs::write(&path, text).unwrap();
+
+        let loaded = DiGraph::from_file(path.to_str().unwrap()).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // O grafo lido tem exatamente as arestas escritas
+        loaded.debug_assert_consistent();
+        assert_eq!(loaded.edges_length(), pairs.len());
+        let mut expected = pairs.clone();
+        expected.sort();
+        let read: Vec<(i32, i32)> = loaded.edges_sorted().iter().map(|e| e.v_w()).collect();
+        assert_eq!(read, expected);
+    }
+
+    #[test]
+    fn edge_weights_between_returns_parallel_edges_in_insertion_order() {
+        let mut g = DiGraph::from_weighted_edges(&[(1, 2, 7), (1, 2, -3), (2, 1, 4), (1, 2, 7)]);
+        g.add_edge(Edge::new_weighted(1, 2, 0));
+        assert_eq!(g.edge_weights_between(1, 2), vec![7, -3, 7, 0]);
+        assert_eq!(g.edge_weights_between(2, 1), vec![4]);
+        assert!(g.edge_weights_between(2, 3).is_empty());
+        assert!(g.edge_weights_between(8, 9).is_empty());
+
+        // Remover as arestas de peso 7 mantém a ordem das restantes
+        g.remove_edge(Edge::new_weighted(1, 2, 7));
+        assert_eq!(g.edge_weights_between(1, 2), vec![-3, 0]);
+        assert_eq!(g.clone().edge_weights_between(1, 2), vec![-3, 0]);
+    }
+
+    #[test]
+    fn map_weights_negation_flips_has_negative_weights() {
+        let mut g = DiGraph::from_weighted_edges(&[(0, 1, 3), (1, 2, 5), (2, 0, 1), (2, 2, 0)]);
+        assert!(!g.has_negative_weights());
+        g.map_weights(|w| -w);
+        g.debug_assert_consistent();
+        assert!(g.has_negative_weights());
+        assert_eq!(g.edge_weights_between(1, 2), vec![-5]);
+        // As cópias em `back_edges` acompanham
+        let mut back: Vec<i32> = g
+            .get_vertice_arc(2)
+            .unwrap()
+            .back_edges_hashmap()
+            .iter()
+            .map(|e| e.weight())
+            .collect();
+        back.sort();
+        assert_eq!(back, vec![-5, 0]);
+
+        // Negar de novo volta ao original; o resultado satura nos limites de `i32`
+        g.map_weights(|w| -w);
+        assert!(!g.has_negative_weights());
+        assert_eq!(g.edge_weights_between(0, 1), vec![3]);
+        g.map_weights(|w| w * i64::from(i32::MAX));
+        assert_eq!(g.edge_weights_between(1, 2), vec![i32::MAX as i64]);
+        g.map_weights(|w| -w - 10);
+        assert_eq!(g.edge_weights_between(1, 2), vec![i32::MIN as i64]);
+    }
+
+    #[test]
+    fn from_file_does_not_trust_huge_header_counts() {
+        let path = std::env::temp_dir().join(format!("graphos_header_{}.txt", std::process::id()));
+        fs::write(&path, "4294967295 4294967295\n").unwrap();
+        let g = DiGraph::from_file(path.to_str().unwrap()).unwrap();
+        assert_eq!((g.vertices_length(), g.edges_length()), (0, 0));
+
+        fs::write(&path, "4294967295 4294967295\n1 2\n2 1\n").unwrap();
+        let g = DiGraph::from_file(path.to_str().unwrap()).unwrap();
+        assert_eq!((g.vertices_length(), g.edges_length()), (2, 2));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn from_file_skips_comments_and_blank_lines() {
+        let path =
+            std::env::temp_dir().join(format!("graphos_comments_{}.txt", std::process::id()));
+        let text = "# grafo de exemplo\n\
+                    % exportado de outra ferramenta\n\
+                    \n\
+                    4 3\n\
+                    1 2\n\
+                    \n\
+                    # ciclo\n\
+                    2 3\n   \n\
+                    \t% comentário indentado\n\
+                    3 1\n";
+        fs::write(&path, text).unwrap();
+        let g = DiGraph::from_file(path.to_str().unwrap()).unwrap();
+        let edges: Vec<(i32, i32)> = g.edges_sorted().iter().map(|e| e.v_w()).collect();
+        assert_eq!(edges, vec![(1, 2), (2, 3), (3, 1)]);
+
+        // Uma linha inválida ainda é rejeitada; um arquivo só com comentários não tem cabeçalho
+        fs::write(&path, "# cabeçalho a seguir\n3 2\n1 2\nx y\n").unwrap();
+        assert!(DiGraph::from_file(path.to_str().unwrap()).is_none());
+        fs::write(&path, "# nada\n\n% aqui\n").unwrap();
+        assert!(DiGraph::from_file(path.to_str().unwrap()).is_none());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn try_queries_report_the_missing_key() {
+        let g = DiGraph::from_weighted_edges(&[(1, 2, 5), (1, 2, 3), (3, 1, 1)]);
+        assert_eq!(g.try_successors(42), Err(VertexNotFound { key: 42 }));
+        assert_eq!(g.try_successor_edges(-7), Err(VertexNotFound { key: -7 }));
+        assert_eq!(g.try_predecessors(8), Err(VertexNotFound { key: 8 }));
+        assert_eq!(g.try_edges_of(9).unwrap_err().key, 9);
+        assert_eq!(
+            VertexNotFound { key: 42 }.to_string(),
+            "vértice 42 não existe no grafo"
+        );
+
+        // Para vértices existentes, o mesmo resultado das versões com `Option`
+        for v in g.vertices_sorted() {
+            assert_eq!(g.try_successors(v).ok(), g.get_sucessor(v));
+            assert_eq!(g.try_successor_edges(v).ok(), g.get_successor_edges(v));
+            assert_eq!(g.try_edges_of(v).ok(), g.edges_of(v));
+            assert_eq!(
+                g.try_predecessors(v).unwrap(),
+                g.predecessor(v).unwrap_or_default()
+            );
+        }
+        assert_eq!(g.try_predecessors(3), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn all_predecessors_matches_per_vertex_queries() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(445);
+        for _ in 0..50 {
+            let mut g = DiGraph::new();
+            g.add_vertice(20);
+            for _ in 0..30 {
+                g.add_edge(Edge::new(rng.gen_range(0..12), rng.gen_range(0..12)));
+            }
+            let all = g.all_predecessors();
+            assert_eq!(all.len(), g.vertices_length());
+            for v in g.vertices_sorted() {
+                let mut expected = g.predecessor(v).unwrap_or_default();
+                expected.sort();
+                assert_eq!(all[&v], expected);
+            }
+            assert!(all[&20].is_empty());
+        }
+
+        // Arestas paralelas repetem a origem
+        let g = DiGraph::from_edge_list(&[(2, 1), (0, 1), (2, 1), (1, 1)]);
+        assert_eq!(g.all_predecessors()[&1], vec![0, 1, 2, 2]);
+        assert!(DiGraph::new().all_predecessors().is_empty());
+    }
+
+    #[test]
+    fn consistency_check_accepts_regular_mutations() {
+        let mut g = DiGraph::from_weighted_edges(&[(1, 2, 3), (2, 3, 4), (3, 1, 5)]);
+        g.add_vertice(7);
+        g.add_edge(Edge::new(7, 1));
+        g.remove_vertice(2);
+        g.debug_assert_consistent();
+        assert_eq!(g.vertices_length(), 3);
+        assert_eq!(g.edges_length(), 2);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "edges_len diverge")]
+    fn consistency_check_fires_on_remove_vertice_after_edge_count_drift() {
+        let mut g = DiGraph::from_edge_list(&[(1, 2), (2, 3)]);
+        g.set_counts_for_test(3, 5);
+        // `add_edge` só faz a verificação barata; a varredura completa pega a divergência
+        g.add_edge(Edge::new(3, 1));
+        g.remove_vertice(1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "arestas 3 -> 1 e arestas de retorno")]
+    fn insertion_check_fires_on_a_missing_back_edge() {
+        let mut g = DiGraph::from_edge_list(&[(1, 2), (3, 1)]);
+        let id = g.get_edges(3, 1).unwrap()[0].id();
+        g.get_vertice_arc_mut(1)
+            .unwrap()
+            .remove_back_edge_by_id(3, id);
+        g.add_edge(Edge::new(3, 1));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "vertices_len diverge")]
+    fn consistency_check_fires_on_add_vertice_after_vertex_count_drift() {
+        let mut g = DiGraph::from_edge_list(&[(1, 2), (2, 3)]);
+        g.set_counts_for_test(1, 2);
+        g.add_vertice(4);
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{tools::heap::HeapMin, DiGraph};
+
+/// Leitura da adjacência de um grafo, sem acesso à estrutura interna.
+///
+/// Permite que um algoritmo rode tanto sobre um `DiGraph` quanto sobre uma visão dele
+/// (como `TransposeView`) sem copiar o grafo.
+#[allow(unused)]
+pub trait GraphView {
+    /// Verifica a existência de um vértice.
+    fn vertice_exists(&self, key: i32) -> bool;
+
+    /// Chaves dos sucessores distintos de um vértice. `None` se o vértice não existir.
+    fn get_sucessor(&self, key: i32) -> Option<Vec<i32>>;
+
+    /// Chama `f(sucessor, peso)` para cada aresta que sai de `key`
+    /// (arestas paralelas são visitadas uma vez cada).
+    fn for_each_successor(&self, key: i32, f: &mut dyn FnMut(i32, i32));
+}
+
+impl GraphView for DiGraph {
+    fn vertice_exists(&self, key: i32) -> bool {
+        DiGraph::vertice_exists(self, key)
+    }
+
+    fn get_sucessor(&self, key: i32) -> Option<Vec<i32>> {
+        DiGraph::get_sucessor(self, key)
+    }
+
+    fn for_each_successor(&self, key: i32, f: &mut dyn FnMut(i32, i32)) {
+        if let Some(vertice) = self.get_vertice_arc(key) {
+            for e in vertice.edges_vec_ref() {
+                f(e.destiny_key(), e.weight());
+            }
+        }
+    }
+}
+
+/// Visão do grafo transposto: os sucessores de um vértice são os seus predecessores no
+/// grafo original. Apenas empresta o `DiGraph`, sem copiar arestas (ver `DiGraph::transpose`).
+pub struct TransposeView<'a> {
+    graph: &'a DiGraph,
+}
+
+#[allow(unused)]
+impl<'a> TransposeView<'a> {
+    pub fn new(graph: &'a DiGraph) -> Self {
+        TransposeView { graph }
+    }
+
+    /// Grafo original
+    pub fn inner(&self) -> &'a DiGraph {
+        self.graph
+    }
+}
+
+impl GraphView for TransposeView<'_> {
+    fn vertice_exists(&self, key: i32) -> bool {
+        self.graph.vertice_exists(key)
+    }
+
+    /// Predecessores distintos de `key` no grafo original, em ordem crescente.
+    fn get_sucessor(&self, key: i32) -> Option<Vec<i32>> {
+        let vertice = self.graph.get_vertice_arc(key)?;
+        let origins: BTreeSet<i32> = vertice
+            .back_edges_vec_ref()
+            .map(|e| e.origin_key())
+            .collect();
+        Some(origins.into_iter().collect())
+    }
+
+    fn for_each_successor(&self, key: i32, f: &mut dyn FnMut(i32, i32)) {
+        if let Some(vertice) = self.graph.get_vertice_arc(key) {
+            for e in vertice.back_edges_vec_ref() {
+                f(e.origin_key(), e.weight());
+            }
+        }
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Visão transposta do grafo, sem cópia (ver `TransposeView`).
+    pub fn transpose_view(&self) -> TransposeView<'_> {
+        TransposeView::new(self)
+    }
+}
+
+/// Distâncias mínimas (Dijkstra) a partir de `source` sobre qualquer `GraphView`.
+///
+/// Sobre uma `TransposeView` calcula as distâncias de cada vértice *até* `source` no grafo
+/// original (Dijkstra reverso). Os pesos devem ser não negativos; vértices inalcançáveis
+/// não aparecem no mapa. Vazio se `source` não existir.
+#[allow(unused)]
+pub fn view_distances(view: &dyn GraphView, source: i32) -> HashMap<i32, i64> {
+    let mut dist: HashMap<i32, i64> = HashMap::new();
+    if !view.vertice_exists(source) {
+        return dist;
+    }
+    dist.insert(source, 0);
+    let mut queue = HeapMin::new(|a: &(i64, i32), b: &(i64, i32)| a.cmp(b));
+    queue.insert((0, source));
+
+    while let Some((d, v)) = queue.pop() {
+        if d > dist[&v] {
+            continue;
+        }
+        let mut successors = Vec::new();
+        view.for_each_successor(v, &mut |w, weight| successors.push((w, weight)));
+        for (w, weight) in successors {
+            let candidate = d + weight as i64;
+            if dist.get(&w).is_none_or(|&current| candidate < current) {
+                dist.insert(w, candidate);
+                queue.insert((candidate, w));
+            }
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn random_graph(rng: &mut StdRng) -> DiGraph {
+        let edges: Vec<(i32, i32, i32)> = (0..16)
+            .map(|_| {
+                (
+                    rng.gen_range(0..8),
+                    rng.gen_range(0..8),
+                    rng.gen_range(0..10),
+                )
+            })
+            .collect();
+        DiGraph::from_weighted_edges(&edges)
+    }
+
+    #[test]
+    fn transpose_view_successors_are_predecessors() {
+        let mut rng = StdRng::seed_from_u64(418);
+        for _ in 0..100 {
+            let g = random_graph(&mut rng);
+            let view = g.transpose_view();
+            let transposed = g.transpose();
+            for v in g.vertices_sorted() {
+                let mut predecessors = g.predecessor(v).unwrap();
+                predecessors.sort();
+                predecessors.dedup();
+                assert_eq!(GraphView::get_sucessor(&view, v), Some(predecessors));
+
+                let (mut seen, mut expected) = (Vec::new(), Vec::new());
+                view.for_each_successor(v, &mut |w, weight| seen.push((w, weight)));
+                transposed.for_each_successor(v, &mut |w, weight| expected.push((w, weight)));
+                seen.sort();
+                expected.sort();
+                assert_eq!(seen, expected);
+            }
+            assert!(!GraphView::vertice_exists(&view, 99));
+            assert_eq!(GraphView::get_sucessor(&view, 99), None);
+        }
+    }
+
+    #[test]
+    fn reverse_dijkstra_on_the_view_matches_the_transpose() {
+        let mut rng = StdRng::seed_from_u64(4180);
+        for _ in 0..100 {
+            let g = random_graph(&mut rng);
+            let target = g.vertices_sorted()[0];
+            assert_eq!(
+                view_distances(&g.transpose_view(), target),
+                view_distances(&g.transpose(), target)
+            );
+        }
+        assert!(view_distances(&DiGraph::new(), 0).is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{tools::inifinity::Infinity, DiGraph};
+
+use Infinity::{Infinite, Number};
+
+/// # Algoritmo de Hopcroft-Karp
+///
+/// Estado auxiliar da busca por um emparelhamento máximo em um grafo bipartido.
+///
+/// `adj`: vizinhos (lado R) de cada vértice do lado L
+///
+/// `pair_l` / `pair_r`: par atual de cada vértice emparelhado
+///
+/// `dist`: camada de cada vértice de L na última busca em largura
+struct HopcroftKarp {
+    left: Vec<i32>,
+    adj: HashMap<i32, Vec<i32>>,
+    pair_l: HashMap<i32, i32>,
+    pair_r: HashMap<i32, i32>,
+    dist: HashMap<i32, Infinity>,
+}
+
+impl HopcroftKarp {
+    fn new(g: &DiGraph, left: Vec<i32>) -> Self {
+        let mut adj = HashMap::with_capacity(left.len());
+        for &u in left.iter() {
+            let mut neighbors: Vec<i32> = g
+                .undirected_neighbors(u)
+                .unwrap_or_default()
+                .into_iter()
+                .collect();
+            neighbors.sort();
+            adj.insert(u, neighbors);
+        }
+        HopcroftKarp {
+            left,
+            adj,
+            pair_l: HashMap::new(),
+            pair_r: HashMap::new(),
+            dist: HashMap::new(),
+        }
+    }
+
+    /// Constrói as camadas a partir dos vértices livres de L.
+    ///
+    /// Retorna `true` se algum caminho aumentante foi encontrado.
+    fn bfs(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for &u in self.left.iter() {
+            if self.pair_l.contains_key(&u) {
+                self.dist.insert(u, Infinite);
+            } else {
+                self.dist.insert(u, Number(0));
+                queue.push_back(u);
+            }
+        }
+
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            let u_dist = self.dist[&u];
+            for &v in self.adj[&u].iter() {
+                match self.pair_r.get(&v) {
+                    // v livre: existe caminho aumentante nesta fase
+                    None => found = true,
+                    Some(&next) => {
+                        if self.dist[&next].is_infinite() {
+                            self.dist.insert(next, u_dist + Number(1));
+                            queue.push_back(next);
+                        }
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Procura um caminho aumentante a partir de `u` seguindo as camadas.
+    fn dfs(&mut self, u: i32) -> bool {
+        let u_dist = self.dist[&u];
+        for i in 0..self.adj[&u].len() {
+            let v = self.adj[&u][i];
+            let augment = match self.pair_r.get(&v) {
+                None => true,
+                Some(&next) => self.dist[&next] == u_dist + Number(1) && self.dfs(next),
+            };
+            if augment {
+                self.pair_l.insert(u, v);
+                self.pair_r.insert(v, u);
+                return true;
+            }
+        }
+        self.dist.insert(u, Infinite);
+        false
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Encontra um emparelhamento máximo em um grafo bipartido usando Hopcroft-Karp.
+    ///
+    /// A direção das arestas é ignorada. Os lados são obtidos por `bipartition`.
+    ///
+    /// Retorna os pares `(l, r)` com `l` no lado L, ordenados por `l`.
+    /// Se o grafo não for bipartido, retorna um vetor vazio.
+    pub fn bipartite_matching(&self) -> Vec<(i32, i32)> {
+        let Some((left, _)) = self.bipartition() else {
+            return vec![];
+        };
+        let mut hk = HopcroftKarp::new(self, left);
+
+        while hk.bfs() {
+            for i in 0..hk.left.len() {
+                let u = hk.left[i];
+                if !hk.pair_l.contains_key(&u) {
+                    hk.dfs(u);
+                }
+            }
+        }
+
+        let mut pairs: Vec<(i32, i32)> = hk.pair_l.into_iter().collect();
+        pairs.sort();
+        pairs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn bipartite_matching_known_size() {
+        // L = {1, 2, 3, 4}, R = {5, 6, 7, 8}; o vértice 8 só liga ao 4, então cabem 4 pares
+        let g = DiGraph::from_edge_list(&[(1, 5), (1, 6), (2, 5), (3, 6), (3, 7), (4, 7), (4, 8)]);
+        let pairs = g.bipartite_matching();
+        assert_eq!(pairs.len(), 4);
+
+        // Cada par é uma aresta e nenhum vértice aparece duas vezes
+        let mut used = HashSet::new();
+        for &(l, r) in pairs.iter() {
+            assert!(g.undirected_neighbors(l).unwrap().contains(&r));
+            assert!(used.insert(l) && used.insert(r));
+        }
+    }
+
+    #[test]
+    fn bipartite_matching_ignores_direction() {
+        // Estrela com arestas em sentidos opostos, mais uma aresta isolada
+        let g = DiGraph::from_edge_list(&[(2, 1), (1, 3), (4, 1), (5, 6)]);
+        assert_eq!(g.bipartite_matching().len(), 2);
+    }
+
+    #[test]
+    fn bipartite_matching_is_empty_for_odd_cycle() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 0), (3, 4)]);
+        assert!(!g.is_bipartite());
+        assert!(g.bipartite_matching().is_empty());
+    }
+}
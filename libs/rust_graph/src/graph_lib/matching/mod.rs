@@ -0,0 +1,2 @@
+pub mod hopcroft_karp;
+pub mod via_flow;
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::{
+    graph_lib::{edge::Edge, flux::ford_fulkerson::max_flux},
+    DiGraph,
+};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Tamanho do emparelhamento máximo calculado por fluxo máximo.
+    ///
+    /// Monta a rede super-fonte -> lado L -> lado R -> super-sumidouro, com todas as
+    /// capacidades unitárias, e executa `max_flux` (Ford-Fulkerson). A direção das arestas
+    /// é ignorada, como em `bipartite_matching`.
+    ///
+    /// Serve como verificação cruzada de `bipartite_matching` (Hopcroft-Karp).
+    /// Retorna 0 se o grafo não for bipartido.
+    pub fn bipartite_matching_via_flow(&self) -> usize {
+        let Some((left, right)) = self.bipartition() else {
+            return 0;
+        };
+        if self.edges_length() == 0 {
+            return 0;
+        }
+
+        let source = self.unused_v_key_from(self.vertices_length() as i32);
+        let sink = self.unused_v_key_from(source + 1);
+        let right_set: HashSet<i32> = right.iter().copied().collect();
+
+        let mut network = DiGraph::new();
+        for &u in left.iter() {
+            network.add_edge(Edge::new_weighted(source, u, 1));
+            let mut neighbors: Vec<i32> = self
+                .undirected_neighbors(u)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|w| right_set.contains(w))
+                .collect();
+            neighbors.sort();
+            for w in neighbors {
+                network.add_edge(Edge::new_weighted(u, w, 1));
+            }
+        }
+        for &w in right.iter() {
+            network.add_edge(Edge::new_weighted(w, sink, 1));
+        }
+
+        let (flux_map, _) = max_flux(&network, source, sink);
+        flux_map.get_max_flux() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn via_flow_agrees_with_hopcroft_karp() {
+        let mut rng = StdRng::seed_from_u64(388);
+        for _ in 0..200 {
+            let left = rng.gen_range(1..8);
+            let right = rng.gen_range(1..8);
+            let mut g = DiGraph::new();
+            for _ in 0..rng.gen_range(0..20) {
+                let (u, w) = (rng.gen_range(0..left), 100 + rng.gen_range(0..right));
+                // Direção aleatória: as duas versões ignoram a direção
+                if rng.gen_bool(0.5) {
+                    g.add_edge(Edge::new(u, w));
+                } else {
+                    g.add_edge(Edge::new(w, u));
+                }
+            }
+            assert_eq!(
+                g.bipartite_matching_via_flow(),
+                g.bipartite_matching().len()
+            );
+        }
+    }
+
+    #[test]
+    fn via_flow_is_zero_for_odd_cycle() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(g.bipartite_matching_via_flow(), 0);
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Calcula a intermediação (betweenness) de cada aresta pelo algoritmo de Brandes.
+    ///
+    /// Para cada par ordenado `(s, t)`, cada caminho mínimo de `s` a `t` contribui com
+    /// `1 / (número de caminhos mínimos)` para cada aresta que atravessa. As distâncias são
+    /// em saltos (BFS, pesos ignorados) e seguem a direção das arestas. Laços nunca estão em
+    /// caminhos mínimos, e arestas paralelas dividem os caminhos entre si.
+    ///
+    /// É o passo central do algoritmo de comunidades de Girvan-Newman: a aresta de maior
+    /// intermediação é a candidata a ser removida.
+    ///
+    /// Retorna um mapa do id de cada aresta do grafo para sua intermediação.
+    pub fn edge_betweenness(&self) -> HashMap<usize, f64> {
+        let mut betweenness: HashMap<usize, f64> =
+            self.all_edges().iter().map(|e| (e.id(), 0.0)).collect();
+
+        for s in self.vertices_sorted() {
+            let mut stack: Vec<i32> = Vec::new();
+            let mut pred_edges: HashMap<i32, Vec<Edge>> = HashMap::new();
+            let mut sigma: HashMap<i32, f64> = HashMap::from([(s, 1.0)]);
+            let mut dist: HashMap<i32, usize> = HashMap::from([(s, 0)]);
+            let mut queue = VecDeque::from([s]);
+
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                for e in self.get_vertice_arc(v).unwrap().edges_sorted() {
+                    let w = e.destiny_key();
+                    if w == v {
+                        continue;
+                    }
+                    if !dist.contains_key(&w) {
+                        dist.insert(w, dist[&v] + 1);
+                        queue.push_back(w);
+                    }
+                    if dist[&w] == dist[&v] + 1 {
+                        *sigma.entry(w).or_insert(0.0) += sigma[&v];
+                        pred_edges.entry(w).or_default().push(e);
+                    }
+                }
+            }
+
+            // Acumula as dependências do vértice mais distante para o mais próximo
+            let mut delta: HashMap<i32, f64> = HashMap::new();
+            while let Some(w) = stack.pop() {
+                let delta_w = delta.get(&w).copied().unwrap_or(0.0);
+                for e in pred_edges.get(&w).into_iter().flatten() {
+                    let v = e.origin_key();
+                    let c = sigma[&v] / sigma[&w] * (1.0 + delta_w);
+                    *betweenness.get_mut(&e.id()).unwrap() += c;
+                    *delta.entry(v).or_insert(0.0) += c;
+                }
+            }
+        }
+        betweenness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Enumera todos os caminhos mínimos de cada par e divide o crédito entre eles
+    fn brute_force(g: &DiGraph) -> HashMap<usize, f64> {
+        fn walk(
+            g: &DiGraph,
+            dist: &HashMap<i32, usize>,
+            v: i32,
+            t: i32,
+            path: &mut Vec<usize>,
+            paths: &mut Vec<Vec<usize>>,
+        ) {
+            if v == t {
+                paths.push(path.clone());
+                return;
+            }
+            for e in g.get_vertice_arc(v).unwrap().edges_sorted() {
+                let w = e.destiny_key();
+                if dist.get(&w) == Some(&(dist[&v] + 1)) && dist[&w] <= dist[&t] {
+                    path.push(e.id());
+                    walk(g, dist, w, t, path, paths);
+                    path.pop();
+                }
+            }
+        }
+        let mut result: HashMap<usize, f64> = g.all_edges().iter().map(|e| (e.id(), 0.0)).collect();
+        for s in g.vertices_sorted() {
+            let mut dist = HashMap::from([(s, 0)]);
+            let mut queue = VecDeque::from([s]);
+            while let Some(v) = queue.pop_front() {
+                for w in g.get_sucessor(v).unwrap() {
+                    if !dist.contains_key(&w) {
+                        dist.insert(w, dist[&v] + 1);
+                        queue.push_back(w);
+                    }
+                }
+            }
+            for &t in dist.keys().filter(|&&t| t != s) {
+                let mut paths = Vec::new();
+                walk(g, &dist, s, t, &mut Vec::new(), &mut paths);
+                for path in paths.iter() {
+                    for id in path {
+                        *result.get_mut(id).unwrap() += 1.0 / paths.len() as f64;
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn barbell_bridge_has_the_highest_betweenness() {
+        // Dois triângulos (arestas nos dois sentidos) ligados pela ponte 2 <-> 3
+        let mut edges = Vec::new();
+        for (a, b) in [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5), (2, 3)] {
+            edges.push((a, b));
+            edges.push((b, a));
+        }
+        let g = DiGraph::from_edge_list(&edges);
+        let betweenness = g.edge_betweenness();
+        let bridge = g.get_edges(2, 3).unwrap()[0].id();
+        let back = g.get_edges(3, 2).unwrap()[0].id();
+        // Todo par (lado esquerdo, lado direito) passa pela ponte
+        assert_eq!(betweenness[&bridge], 9.0);
+        assert_eq!(betweenness[&back], 9.0);
+        let others = betweenness
+            .iter()
+            .filter(|(&id, _)| id != bridge && id != back)
+            .map(|(_, &value)| value);
+        assert!(others.fold(0.0, f64::max) < 9.0);
+    }
+
+    #[test]
+    fn edge_betweenness_matches_path_enumeration() {
+        let mut rng = StdRng::seed_from_u64(391);
+        for _ in 0..200 {
+            let mut g = DiGraph::new();
+            for v in 0..6 {
+                g.add_vertice(v);
+            }
+            for _ in 0..rng.gen_range(0..14) {
+                g.add_edge(Edge::new(rng.gen_range(0..6), rng.gen_range(0..6)));
+            }
+            let expected = brute_force(&g);
+            let betweenness = g.edge_betweenness();
+            assert_eq!(betweenness.len(), expected.len());
+            for (id, value) in expected {
+                assert!((betweenness[&id] - value).abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use crate::DiGraph;
+
+use super::clustering::simple_neighbors;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Enumera todos os cliques maximais, ignorando a direção das arestas (e laços).
+    ///
+    /// Bron-Kerbosch com pivô (Tomita): o pivô é o candidato com mais vizinhos em `P ∪ X`.
+    /// O número de cliques maximais pode ser **exponencial** (até 3^(n/3)), então é indicado
+    /// para grafos pequenos ou esparsos. Vértices isolados formam cliques de um vértice.
+    ///
+    /// Cada clique vem em ordem crescente, e a lista é ordenada.
+    pub fn maximal_cliques(&self) -> Vec<Vec<i32>> {
+        let neighbors: HashMap<i32, HashSet<i32>> = self
+            .get_vertice_key_array()
+            .into_iter()
+            .map(|v| (v, simple_neighbors(self, v).unwrap_or_default()))
+            .collect();
+        let candidates: HashSet<i32> = neighbors.keys().copied().collect();
+        if candidates.is_empty() {
+            return Vec::new(); // Sem vértices não há cliques (nem o vazio)
+        }
+
+        let mut cliques = Vec::new();
+        bron_kerbosch(&neighbors, &mut Vec::new(), candidates, HashSet::new(), &mut cliques);
+        for clique in cliques.iter_mut() {
+            clique.sort();
+        }
+        cliques.sort();
+        cliques
+    }
+}
+
+/// `r`: clique atual, `p`: candidatos a estender `r`, `x`: vértices já tratados
+fn bron_kerbosch(
+    neighbors: &HashMap<i32, HashSet<i32>>,
+    r: &mut Vec<i32>,
+    mut p: HashSet<i32>,
+    mut x: HashSet<i32>,
+    cliques: &mut Vec<Vec<i32>>,
+) {
+    if p.is_empty() {
+        if x.is_empty() {
+            cliques.push(r.clone());
+        }
+        return;
+    }
+    let pivot = *p
+        .union(&x)
+        .max_by_key(|&&u| (neighbors[&u].intersection(&p).count(), Reverse(u)))
+        .unwrap();
+
+    let mut branch: Vec<i32> = p.difference(&neighbors[&pivot]).copied().collect();
+    branch.sort();
+    for v in branch {
+        r.push(v);
+        let p_v = p.intersection(&neighbors[&v]).copied().collect();
+        let x_v = x.intersection(&neighbors[&v]).copied().collect();
+        bron_kerbosch(neighbors, r, p_v, x_v, cliques);
+        r.pop();
+        p.remove(&v);
+        x.insert(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn two_triangles_sharing_an_edge() {
+        // Triângulos {1, 2, 3} e {2, 3, 4}, com 5 pendurado em 4 e 6 isolado
+        let mut g =
+            DiGraph::from_edge_list(&[(1, 2), (2, 3), (3, 1), (3, 4), (4, 2), (5, 4), (4, 5)]);
+        g.add_vertice(6);
+        assert_eq!(
+            g.maximal_cliques(),
+            vec![vec![1, 2, 3], vec![2, 3, 4], vec![4, 5], vec![6]]
+        );
+        assert!(DiGraph::new().maximal_cliques().is_empty());
+    }
+
+    #[test]
+    fn cliques_with_extreme_keys() {
+        // Empate no pivô com `i32::MIN` entre os candidatos
+        let g =
+            DiGraph::from_edge_list(&[(i32::MIN, i32::MAX), (i32::MAX, 0), (0, i32::MIN), (0, 1)]);
+        assert_eq!(
+            g.maximal_cliques(),
+            vec![vec![i32::MIN, 0, i32::MAX], vec![0, 1]]
+        );
+    }
+
+    #[test]
+    fn maximal_cliques_match_brute_force() {
+        let mut rng = StdRng::seed_from_u64(409);
+        for _ in 0..100 {
+            let mut g = DiGraph::new();
+            for v in 0..7 {
+                g.add_vertice(v);
+            }
+            for _ in 0..12 {
+                g.add_edge(Edge::new(rng.gen_range(0..7), rng.gen_range(0..7)));
+            }
+            let adjacent = |a: i32, b: i32| g.has_edge(a, b) || g.has_edge(b, a);
+            let is_clique = |set: &[i32]| {
+                set.iter()
+                    .enumerate()
+                    .all(|(i, &a)| set[i + 1..].iter().all(|&b| adjacent(a, b)))
+            };
+
+            let mut expected = Vec::new();
+            for mask in 1u32..(1 << 7) {
+                let set: Vec<i32> = (0..7).filter(|v| mask & (1 << v) != 0).collect();
+                let maximal =
+                    (0..7).all(|v| set.contains(&v) || !set.iter().all(|&u| adjacent(u, v)));
+                if is_clique(&set) && maximal {
+                    expected.push(set);
+                }
+            }
+            expected.sort();
+            assert_eq!(g.maximal_cliques(), expected);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::DiGraph;
+
+/// Vizinhos de `key` sem considerar direção e sem o próprio vértice (laços são ignorados).
+pub fn simple_neighbors(g: &DiGraph, key: i32) -> Option<HashSet<i32>> {
+    let mut neighbors = g.undirected_neighbors(key)?;
+    neighbors.remove(&key);
+    Some(neighbors)
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Conta os triângulos (cliques de 3 vértices) do grafo, ignorando a direção das arestas.
+    ///
+    /// Para cada aresta {u, v} com u < v, conta os vizinhos comuns w com w > v,
+    /// assim cada triângulo é contado exatamente uma vez.
+    /// Arestas paralelas e laços não formam triângulos extras.
+    pub fn triangle_count(&self) -> usize {
+        let neighbors: HashMap<i32, HashSet<i32>> = self
+            .get_vertice_key_array()
+            .into_iter()
+            .map(|v| (v, simple_neighbors(self, v).unwrap_or_default()))
+            .collect();
+
+        let mut count = 0;
+        for (&u, u_neighbors) in neighbors.iter() {
+            for &v in u_neighbors.iter().filter(|&&v| v > u) {
+                count += u_neighbors
+                    .intersection(&neighbors[&v])
+                    .filter(|&&w| w > v)
+                    .count();
+            }
+        }
+        count
+    }
+
+    /// Coeficiente de agrupamento local de um vértice (direção ignorada).
+    ///
+    /// `C(v) = 2 * L / (k * (k - 1))`, onde `k` é o número de vizinhos de `v`
+    /// e `L` o número de arestas entre esses vizinhos.
+    ///
+    /// Vértices com menos de 2 vizinhos têm coeficiente `0.0`.
+    /// Retorna `None` se o vértice não existir.
+    pub fn clustering_coefficient(&self, key: i32) -> Option<f64> {
+        let neighbors: Vec<i32> = simple_neighbors(self, key)?.into_iter().collect();
+        let k = neighbors.len();
+        if k < 2 {
+            return Some(0.0);
+        }
+
+        let mut links = 0;
+        for (i, &a) in neighbors.iter().enumerate() {
+            for &b in neighbors.iter().skip(i + 1) {
+                if self.has_edge(a, b) || self.has_edge(b, a) {
+                    links += 1;
+                }
+            }
+        }
+        Some(2.0 * links as f64 / (k * (k - 1)) as f64)
+    }
+
+    /// Média dos coeficientes de agrupamento locais de todos os vértices.
+    ///
+    /// Vértices com menos de 2 vizinhos entram na média com coeficiente `0.0`.
+    ///
+    /// Não confundir com `global_clustering_coefficient`: aqui todos os vértices têm o mesmo peso,
+    /// enquanto na transitividade vértices de grau alto (com mais triplas) pesam mais.
+    pub fn average_clustering_coefficient(&self) -> f64 {
+        let len = self.vertices_length();
+        if len == 0 {
+            return 0.0;
+        }
+        let sum: f64 = self
+            .iter_vertices()
+            .filter_map(|v| self.clustering_coefficient(v.key()))
+            .sum();
+        sum / len as f64
+    }
+
+    /// Coeficiente de agrupamento global (transitividade).
+    ///
+    /// `T = 3 * triângulos / triplas conectadas`, onde uma tripla conectada é
+    /// um caminho de dois passos centrado em algum vértice (`k * (k - 1) / 2` por vértice).
+    ///
+    /// Retorna `0.0` se o grafo não possui triplas conectadas.
+    pub fn global_clustering_coefficient(&self) -> f64 {
+        let triples: usize = self
+            .get_vertice_key_array()
+            .into_iter()
+            .map(|v| {
+                let k = simple_neighbors(self, v).map_or(0, |n| n.len());
+                k * k.saturating_sub(1) / 2
+            })
+            .sum();
+        if triples == 0 {
+            return 0.0;
+        }
+        3.0 * self.triangle_count() as f64 / triples as f64
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Peso total entre `a` e `b` sem considerar direção (soma das arestas nos dois sentidos).
+    fn undirected_weight(&self, a: i32, b: i32) -> i64 {
+        [(a, b), (b, a)]
+            .iter()
+            .filter_map(|&(v, w)| self.get_edges(v, w))
+            .flatten()
+            .map(|e| e.weight() as i64)
+            .sum()
+    }
+
+    /// Coeficiente de agrupamento local ponderado de Barrat (direção ignorada).
+    ///
+    /// `C_w(v) = 1 / (s * (k - 1)) * Σ (w_va + w_vb)`, somando sobre os pares de vizinhos
+    /// `{a, b}` ligados entre si, onde `k` é o número de vizinhos, `s` a força do vértice
+    /// (`out_strength + in_strength`, sem laços) e `w_va` o peso total entre `v` e `a`.
+    /// Com pesos iguais coincide com `clustering_coefficient`.
+    ///
+    /// Vértices com menos de 2 vizinhos ou força não positiva têm coeficiente `0.0`.
+    /// Retorna `None` se o vértice não existir.
+    pub fn weighted_clustering_coefficient(&self, key: i32) -> Option<f64> {
+        let neighbors: Vec<i32> = simple_neighbors(self, key)?.into_iter().collect();
+        let k = neighbors.len();
+        let loops = self.get_edges(key, key).map_or(0, |edges| {
+            edges.iter().map(|e| e.weight() as i64).sum::<i64>()
+        });
+        let strength = self.out_strength(key)? + self.in_strength(key)? - 2 * loops;
+        if k < 2 || strength <= 0 {
+            return Some(0.0);
+        }
+
+        let weights: Vec<i64> = neighbors
+            .iter()
+            .map(|&a| self.undirected_weight(key, a))
+            .collect();
+        let mut total = 0;
+        for (i, &a) in neighbors.iter().enumerate() {
+            for (j, &b) in neighbors.iter().enumerate().skip(i + 1) {
+                if self.has_edge(a, b) || self.has_edge(b, a) {
+                    total += weights[i] + weights[j];
+                }
+            }
+        }
+        Some(total as f64 / (strength as f64 * (k - 1) as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete(n: i32) -> DiGraph {
+        let mut edges = Vec::new();
+        for v in 0..n {
+            for w in v + 1..n {
+                edges.push((v, w));
+            }
+        }
+        DiGraph::from_edge_list(&edges)
+    }
+
+    #[test]
+    fn triangles_of_k4_and_star() {
+        let k4 = complete(4);
+        assert_eq!(k4.triangle_count(), 4);
+        for v in 0..4 {
+            assert_eq!(k4.clustering_coefficient(v), Some(1.0));
+        }
+
+        let star = DiGraph::from_edge_list(&[(0, 1), (0, 2), (0, 3), (4, 0)]);
+        assert_eq!(star.triangle_count(), 0);
+        assert_eq!(star.clustering_coefficient(0), Some(0.0));
+        assert_eq!(star.clustering_coefficient(1), Some(0.0));
+        assert_eq!(star.clustering_coefficient(9), None);
+    }
+
+    #[test]
+    fn parallel_edges_and_loops_do_not_add_triangles() {
+        // Triângulo com as arestas nos dois sentidos, uma paralela e um laço
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 0), (1, 2), (1, 2), (2, 0), (0, 0)]);
+        assert_eq!(g.triangle_count(), 1);
+        assert_eq!(g.clustering_coefficient(0), Some(1.0));
+    }
+
+    #[test]
+    fn average_and_global_clustering_differ() {
+        // Triângulo 0-1-2 com o vértice 3 pendurado em 2:
+        // C = [1, 1, 1/3, 0] e triplas conectadas = 1 + 1 + 3 + 0
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+        assert!((g.average_clustering_coefficient() - 7.0 / 12.0).abs() < 1e-12);
+        assert!((g.global_clustering_coefficient() - 0.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn clustering_of_graphs_without_triples() {
+        assert_eq!(DiGraph::new().average_clustering_coefficient(), 0.0);
+        let g = DiGraph::from_edge_list(&[(0, 1), (2, 3)]);
+        assert_eq!(g.average_clustering_coefficient(), 0.0);
+        assert_eq!(g.global_clustering_coefficient(), 0.0);
+    }
+
+    #[test]
+    fn weighted_clustering_follows_heavy_triangle_edges() {
+        // Vizinhos de 0: 1, 2 e 3; só 1 - 2 fecha um triângulo
+        let heavy_triangle =
+            DiGraph::from_weighted_edges(&[(0, 1, 5), (0, 2, 5), (0, 3, 1), (1, 2, 1)]);
+        let light_triangle =
+            DiGraph::from_weighted_edges(&[(0, 1, 1), (2, 0, 1), (0, 3, 8), (1, 2, 1)]);
+        for g in [&heavy_triangle, &light_triangle] {
+            assert!((g.clustering_coefficient(0).unwrap() - 1.0 / 3.0).abs() < 1e-12);
+        }
+        // (5 + 5) / (11 * 2) e (1 + 1) / (10 * 2)
+        assert!(
+            (heavy_triangle.weighted_clustering_coefficient(0).unwrap() - 10.0 / 22.0).abs()
+                < 1e-12
+        );
+        assert!((light_triangle.weighted_clustering_coefficient(0).unwrap() - 0.1).abs() < 1e-12);
+        assert_eq!(heavy_triangle.weighted_clustering_coefficient(3), Some(0.0));
+        assert_eq!(heavy_triangle.weighted_clustering_coefficient(9), None);
+    }
+
+    #[test]
+    fn equal_weights_match_unweighted_clustering() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(413);
+        for _ in 0..100 {
+            let mut edges = Vec::new();
+            for v in 0..7 {
+                for w in v + 1..7 {
+                    if rng.gen_bool(0.4) {
+                        edges.push(if rng.gen_bool(0.5) {
+                            (v, w, 4)
+                        } else {
+                            (w, v, 4)
+                        });
+                    }
+                }
+            }
+            let g = DiGraph::from_weighted_edges(&edges);
+            for v in g.vertices_sorted() {
+                let weighted = g.weighted_clustering_coefficient(v).unwrap();
+                assert!((weighted - g.clustering_coefficient(v).unwrap()).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::DiGraph;
+
+/// Erro em uma partição dos vértices em comunidades
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartitionError {
+    /// A partição cita uma chave que não é vértice do grafo
+    UnknownKey(i32),
+    /// A mesma chave aparece em mais de uma comunidade (ou duas vezes na mesma)
+    DuplicateKey(i32),
+    /// Um vértice do grafo não aparece em nenhuma comunidade
+    MissingVertex(i32),
+}
+
+impl fmt::Display for PartitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartitionError::UnknownKey(key) => write!(f, "chave {} não existe no grafo", key),
+            PartitionError::DuplicateKey(key) => write!(f, "chave {} repetida", key),
+            PartitionError::MissingVertex(key) => {
+                write!(f, "vértice {} fora de todas as comunidades", key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PartitionError {}
+
+/// Grafo agregado do Louvain: cada nó é uma comunidade do nível anterior
+struct LouvainLevel {
+    /// Pesos para os outros nós (simétrico, sem laços)
+    adj: Vec<BTreeMap<usize, f64>>,
+    /// Peso interno de cada nó (laços e arestas já agregadas)
+    self_loops: Vec<f64>,
+    /// Vértices originais (índices em `vertices_sorted`) dentro de cada nó
+    members: Vec<Vec<usize>>,
+}
+
+impl LouvainLevel {
+    /// Força de cada nó: pesos para os vizinhos mais duas vezes o peso interno
+    fn strengths(&self) -> Vec<f64> {
+        self.adj
+            .iter()
+            .zip(self.self_loops.iter())
+            .map(|(neighbors, loops)| neighbors.values().sum::<f64>() + 2.0 * loops)
+            .collect()
+    }
+
+    /// Fase 1: move nós para a comunidade vizinha de maior ganho de modularidade até
+    /// nada mudar. Retorna a comunidade de cada nó e se algum nó mudou.
+    fn local_moves(&self, total: f64, rng: &mut StdRng) -> (Vec<usize>, bool) {
+        let strength = self.strengths();
+        let mut community: Vec<usize> = (0..self.adj.len()).collect();
+        let mut community_strength = strength.clone();
+        let mut order: Vec<usize> = (0..self.adj.len()).collect();
+        let mut moved_any = false;
+
+        loop {
+            order.shuffle(rng);
+            let mut moved = false;
+            for &i in order.iter() {
+                let current = community[i];
+                community_strength[current] -= strength[i];
+
+                let mut links: BTreeMap<usize, f64> = BTreeMap::from([(current, 0.0)]);
+                for (&j, &weight) in self.adj[i].iter() {
+                    *links.entry(community[j]).or_insert(0.0) += weight;
+                }
+                // Ganho de inserir `i` em `c`, sem as constantes comuns a todas as comunidades
+                let gain =
+                    |c: usize| links[&c] - community_strength[c] * strength[i] / (2.0 * total);
+                let mut best = current;
+                let mut best_gain = gain(current);
+                for &c in links.keys() {
+                    if gain(c) > best_gain + 1e-12 {
+                        best = c;
+                        best_gain = gain(c);
+                    }
+                }
+
+                community_strength[best] += strength[i];
+                if best != current {
+                    community[i] = best;
+                    moved = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+            moved_any = true;
+        }
+        (community, moved_any)
+    }
+
+    /// Fase 2: cada comunidade vira um nó do próximo nível.
+    fn aggregate(self, community: &[usize]) -> LouvainLevel {
+        let mut renumber: BTreeMap<usize, usize> = BTreeMap::new();
+        for &c in community.iter() {
+            let next = renumber.len();
+            renumber.entry(c).or_insert(next);
+        }
+        let len = renumber.len();
+        let mut level = LouvainLevel {
+            adj: vec![BTreeMap::new(); len],
+            self_loops: vec![0.0; len],
+            members: vec![Vec::new(); len],
+        };
+        for (i, neighbors) in self.adj.iter().enumerate() {
+            let c = renumber[&community[i]];
+            level.self_loops[c] += self.self_loops[i];
+            level.members[c].extend_from_slice(&self.members[i]);
+            for (&j, &weight) in neighbors.iter() {
+                let d = renumber[&community[j]];
+                if c == d {
+                    // Cada aresta aparece nos dois sentidos
+                    level.self_loops[c] += weight / 2.0;
+                } else {
+                    *level.adj[c].entry(d).or_insert(0.0) += weight;
+                }
+            }
+        }
+        level
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Modularidade de Newman (`Q`) da partição `communities`, ignorando a direção das arestas.
+    ///
+    /// `Q = Σ_c [ L_c / m - (K_c / 2m)² ]`, onde `m` é a soma dos pesos de todas as arestas,
+    /// `L_c` a soma dos pesos das arestas dentro da comunidade `c` e `K_c` a soma das forças
+    /// (`in_strength + out_strength`) dos seus vértices. Um laço soma seu peso duas vezes à
+    /// força do vértice. Vai de `-0.5` a `1.0`; grafo sem peso total tem modularidade `0.0`.
+    ///
+    /// Cada vértice do grafo deve estar em exatamente uma comunidade; caso contrário
+    /// retorna `PartitionError`.
+    pub fn modularity(&self, communities: &[Vec<i32>]) -> Result<f64, PartitionError> {
+        let community = self.community_index(communities)?;
+
+        let mut total = 0.0;
+        let mut inner = vec![0.0; communities.len()];
+        let mut strength = vec![0.0; communities.len()];
+        for e in self.all_edges() {
+            let weight = e.weight() as f64;
+            let (c_v, c_w) = (community[&e.origin_key()], community[&e.destiny_key()]);
+            total += weight;
+            strength[c_v] += weight;
+            strength[c_w] += weight;
+            if c_v == c_w {
+                inner[c_v] += weight;
+            }
+        }
+        if total == 0.0 {
+            return Ok(0.0);
+        }
+
+        Ok(inner
+            .iter()
+            .zip(strength.iter())
+            .map(|(l, k)| l / total - (k / (2.0 * total)).powi(2))
+            .sum())
+    }
+
+    /// Índice da comunidade de cada vértice, validando que a partição cobre o grafo.
+    fn community_index(
+        &self,
+        communities: &[Vec<i32>],
+    ) -> Result<HashMap<i32, usize>, PartitionError> {
+        let mut index: HashMap<i32, usize> = HashMap::with_capacity(self.vertices_length());
+        for (c, members) in communities.iter().enumerate() {
+            for &key in members {
+                if !self.vertice_exists(key) {
+                    return Err(PartitionError::UnknownKey(key));
+                }
+                if index.insert(key, c).is_some() {
+                    return Err(PartitionError::DuplicateKey(key));
+                }
+            }
+        }
+        if let Some(key) = self
+            .vertices_sorted()
+            .into_iter()
+            .find(|k| !index.contains_key(k))
+        {
+            return Err(PartitionError::MissingVertex(key));
+        }
+        Ok(index)
+    }
+
+    /// Detecção de comunidades pelo método de Louvain, ignorando a direção das arestas.
+    ///
+    /// Alterna duas fases até a modularidade (ver `modularity`) parar de subir: cada vértice
+    /// vai para a comunidade vizinha com maior ganho, e depois cada comunidade vira um único
+    /// vértice do grafo agregado. A ordem de visita é embaralhada a partir de `seed`, então o
+    /// resultado é determinístico para a mesma semente. Supõe pesos não negativos.
+    ///
+    /// Cada comunidade vem em ordem crescente, e as comunidades são ordenadas pelo menor
+    /// vértice. Sem arestas (ou com peso total zero), cada vértice fica sozinho.
+    pub fn louvain(&self, seed: u64) -> Vec<Vec<i32>> {
+        let keys = self.vertices_sorted();
+        let index: HashMap<i32, usize> = keys.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+        let mut level = LouvainLevel {
+            adj: vec![BTreeMap::new(); keys.len()],
+            self_loops: vec![0.0; keys.len()],
+            members: (0..keys.len()).map(|i| vec![i]).collect(),
+        };
+        let mut total = 0.0;
+        for e in self.edges_sorted() {
+            let (v, w) = (index[&e.origin_key()], index[&e.destiny_key()]);
+            let weight = e.weight() as f64;
+            total += weight;
+            if v == w {
+                level.self_loops[v] += weight;
+            } else {
+                *level.adj[v].entry(w).or_insert(0.0) += weight;
+                *level.adj[w].entry(v).or_insert(0.0) += weight;
+            }
+        }
+
+        if total > 0.0 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            loop {
+                let (community, moved) = level.local_moves(total, &mut rng);
+                if !moved {
+                    break;
+                }
+                level = level.aggregate(&community);
+            }
+        }
+
+        let mut communities: Vec<Vec<i32>> = level
+            .members
+            .into_iter()
+            .map(|members| {
+                let mut group: Vec<i32> = members.into_iter().map(|i| keys[i]).collect();
+                group.sort();
+                group
+            })
+            .collect();
+        communities.sort();
+        communities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::Rng;
+
+    /// Dois triângulos ligados pela aresta 2 -> 3
+    fn two_triangles() -> DiGraph {
+        DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)])
+    }
+
+    /// Modularidade pela definição com a matriz simétrica `A`:
+    /// `Q = 1/2m Σ_ij [A_ij - k_i k_j / 2m] δ(c_i, c_j)`
+    fn modularity_by_definition(g: &DiGraph, communities: &[Vec<i32>]) -> f64 {
+        let mut a: HashMap<(i32, i32), f64> = HashMap::new();
+        let mut k: HashMap<i32, f64> = HashMap::new();
+        let mut total = 0.0;
+        for e in g.all_edges() {
+            let (v, w) = e.v_w();
+            let weight = e.weight() as f64;
+            total += weight;
+            *a.entry((v, w)).or_default() += weight;
+            *a.entry((w, v)).or_default() += weight;
+            *k.entry(v).or_default() += weight;
+            *k.entry(w).or_default() += weight;
+        }
+        let mut q = 0.0;
+        for members in communities {
+            for i in members {
+                for j in members {
+                    let a_ij = a.get(&(*i, *j)).copied().unwrap_or(0.0);
+                    let (k_i, k_j) = (
+                        k.get(i).copied().unwrap_or(0.0),
+                        k.get(j).copied().unwrap_or(0.0),
+                    );
+                    q += a_ij - k_i * k_j / (2.0 * total);
+                }
+            }
+        }
+        q / (2.0 * total)
+    }
+
+    #[test]
+    fn modularity_of_two_triangles() {
+        let g = two_triangles();
+        // m = 7, cada triângulo tem L = 3 e K = 7: Q = 2 (3/7 - 1/4) = 5/14
+        let q = g.modularity(&[vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+        assert!((q - 5.0 / 14.0).abs() < 1e-12);
+
+        // Partições piores têm modularidade menor
+        let all = g.modularity(&[vec![0, 1, 2, 3, 4, 5]]).unwrap();
+        assert!(all.abs() < 1e-12);
+        let mixed = g.modularity(&[vec![0, 1, 3], vec![2, 4, 5]]).unwrap();
+        assert!(mixed < q);
+        let singletons: Vec<Vec<i32>> = (0..6).map(|v| vec![v]).collect();
+        assert!(g.modularity(&singletons).unwrap() < 0.0);
+
+        assert_eq!(DiGraph::new().modularity(&[]), Ok(0.0));
+    }
+
+    #[test]
+    fn modularity_rejects_invalid_partitions() {
+        let g = two_triangles();
+        assert_eq!(
+            g.modularity(&[vec![0, 1, 2], vec![3, 4]]),
+            Err(PartitionError::MissingVertex(5))
+        );
+        assert_eq!(
+            g.modularity(&[vec![0, 1, 2, 3], vec![3, 4, 5]]),
+            Err(PartitionError::DuplicateKey(3))
+        );
+        assert_eq!(
+            g.modularity(&[vec![0, 1, 2, 9], vec![3, 4, 5]]),
+            Err(PartitionError::UnknownKey(9))
+        );
+        assert_eq!(
+            PartitionError::MissingVertex(5).to_string(),
+            "vértice 5 fora de todas as comunidades"
+        );
+    }
+
+    #[test]
+    fn modularity_matches_the_definition() {
+        let mut rng = StdRng::seed_from_u64(442);
+        for _ in 0..200 {
+            let mut g = DiGraph::new();
+            for v in 0..7 {
+                g.add_vertice(v);
+            }
+            for _ in 0..10 {
+                let (v, w) = (rng.gen_range(0..7), rng.gen_range(0..7));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(1..5)));
+            }
+            let mut communities: Vec<Vec<i32>> = vec![Vec::new(); 3];
+            for v in 0..7 {
+                communities[rng.gen_range(0..3)].push(v);
+            }
+            let q = g.modularity(&communities).unwrap();
+            assert!((q - modularity_by_definition(&g, &communities)).abs() < 1e-9);
+            assert!((-0.5..=1.0).contains(&q));
+        }
+    }
+
+    #[test]
+    fn louvain_recovers_two_separated_cliques() {
+        // Duas cliques de 5 vértices (10..15 e 20..25) ligadas por uma única aresta leve
+        let mut g = DiGraph::new();
+        for base in [10, 20] {
+            for v in base..base + 5 {
+                for w in v + 1..base + 5 {
+                    g.add_edge(Edge::new_weighted(v, w, 3));
+                }
+            }
+        }
+        g.add_edge(Edge::new(14, 20));
+        let expected = vec![vec![10, 11, 12, 13, 14], vec![20, 21, 22, 23, 24]];
+        for seed in 0..10 {
+            assert_eq!(g.louvain(seed), expected);
+        }
+
+        // Sem arestas cada vértice fica sozinho
+        let mut empty = DiGraph::new();
+        empty.add_vertice(2);
+        empty.add_vertice(1);
+        assert_eq!(empty.louvain(443), vec![vec![1], vec![2]]);
+        assert!(DiGraph::new().louvain(443).is_empty());
+    }
+
+    #[test]
+    fn louvain_is_deterministic_and_a_valid_improving_partition() {
+        let mut rng = StdRng::seed_from_u64(443);
+        for _ in 0..50 {
+            let mut g = DiGraph::new();
+            for v in 0..12 {
+                g.add_vertice(v);
+            }
+            for _ in 0..20 {
+                let (v, w) = (rng.gen_range(0..12), rng.gen_range(0..12));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(1..5)));
+            }
+            let seed = rng.gen();
+            let communities = g.louvain(seed);
+            assert_eq!(g.louvain(seed), communities);
+
+            // Partição válida, ordenada, e pelo menos tão boa quanto vértices isolados
+            let q = g.modularity(&communities).unwrap();
+            for group in communities.iter() {
+                assert!(group.windows(2).all(|p| p[0] < p[1]));
+            }
+            assert!(communities.windows(2).all(|p| p[0][0] < p[1][0]));
+            let singletons: Vec<Vec<i32>> = (0..12).map(|v| vec![v]).collect();
+            assert!(q >= g.modularity(&singletons).unwrap() - 1e-12);
+            assert!(q >= -1e-12);
+        }
+    }
+}
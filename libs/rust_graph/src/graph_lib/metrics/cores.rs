@@ -0,0 +1,119 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::DiGraph;
+
+use super::clustering::simple_neighbors;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Calcula o número de núcleo (coreness) de cada vértice pelo algoritmo de remoção sucessiva.
+    ///
+    /// O grau usado é o não direcionado (vizinhos distintos, sem laços).
+    /// Repetidamente remove o vértice de menor grau restante; seu número de núcleo é
+    /// o maior grau mínimo visto até o momento da remoção.
+    pub fn core_numbers(&self) -> HashMap<i32, usize> {
+        let neighbors: HashMap<i32, HashSet<i32>> = self
+            .get_vertice_key_array()
+            .into_iter()
+            .map(|v| (v, simple_neighbors(self, v).unwrap_or_default()))
+            .collect();
+
+        let mut degree: HashMap<i32, usize> =
+            neighbors.iter().map(|(&v, n)| (v, n.len())).collect();
+        let mut queue: BTreeSet<(usize, i32)> = degree.iter().map(|(&v, &d)| (d, v)).collect();
+        let mut cores: HashMap<i32, usize> = HashMap::with_capacity(degree.len());
+        let mut k = 0;
+
+        while let Some((d, v)) = queue.pop_first() {
+            k = k.max(d);
+            cores.insert(v, k);
+            for &w in neighbors[&v].iter() {
+                if cores.contains_key(&w) {
+                    continue;
+                }
+                let w_degree = degree.get_mut(&w).unwrap();
+                queue.remove(&(*w_degree, w));
+                *w_degree -= 1;
+                queue.insert((*w_degree, w));
+            }
+        }
+        cores
+    }
+
+    /// Retorna os vértices do k-núcleo: o maior subgrafo em que todo vértice tem grau ≥ `k`.
+    ///
+    /// Os vértices são retornados em ordem crescente.
+    pub fn k_core(&self, k: usize) -> Vec<i32> {
+        let mut core: Vec<i32> = self
+            .core_numbers()
+            .into_iter()
+            .filter(|&(_, c)| c >= k)
+            .map(|(v, _)| v)
+            .collect();
+        core.sort();
+        core
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// k-núcleo removendo vértices de grau < k até nada mudar
+    fn peel(g: &DiGraph, k: usize) -> Vec<i32> {
+        let mut alive: HashSet<i32> = g.get_vertice_key_array().into_iter().collect();
+        loop {
+            let weak: Vec<i32> = alive
+                .iter()
+                .copied()
+                .filter(|&v| {
+                    let n = simple_neighbors(g, v).unwrap();
+                    n.iter().filter(|w| alive.contains(w)).count() < k
+                })
+                .collect();
+            if weak.is_empty() {
+                let mut core: Vec<i32> = alive.into_iter().collect();
+                core.sort();
+                return core;
+            }
+            for v in weak {
+                alive.remove(&v);
+            }
+        }
+    }
+
+    #[test]
+    fn k4_is_a_3_core_and_periphery_is_not() {
+        // K4 em {0, 1, 2, 3}; 6 liga a dois vértices do K4 e 4-5 formam uma cauda
+        let mut edges = vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        edges.extend([(4, 0), (5, 4), (6, 1), (2, 6)]);
+        let g = DiGraph::from_edge_list(&edges);
+
+        let cores = g.core_numbers();
+        let expected = HashMap::from([(0, 3), (1, 3), (2, 3), (3, 3), (4, 1), (5, 1), (6, 2)]);
+        assert_eq!(cores, expected);
+        assert_eq!(g.k_core(3), vec![0, 1, 2, 3]);
+        assert_eq!(g.k_core(2), vec![0, 1, 2, 3, 6]);
+        assert!(g.k_core(4).is_empty());
+    }
+
+    #[test]
+    fn k_core_matches_naive_peeling() {
+        let mut rng = StdRng::seed_from_u64(358);
+        for _ in 0..200 {
+            let n = rng.gen_range(1..15);
+            let mut g = DiGraph::new();
+            for v in 0..n {
+                g.add_vertice(v);
+            }
+            for _ in 0..rng.gen_range(0..40) {
+                g.add_edge(Edge::new(rng.gen_range(0..n), rng.gen_range(0..n)));
+            }
+            for k in 0..6 {
+                assert_eq!(g.k_core(k), peel(&g, k));
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{graph_lib::vertice::Vertice, DiGraph};
+
+/// Quanto um laço soma ao grau não direcionado de um vértice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(unused)]
+pub enum DegreeConvention {
+    /// O laço soma 2 (uma vez por extremidade), convenção mais comum e que mantém
+    /// a soma dos graus igual a `2 * arestas`
+    #[default]
+    LoopCountsTwice,
+    /// O laço soma 1
+    LoopCountsOnce,
+}
+
+impl DegreeConvention {
+    /// Grau não direcionado de `v` segundo a convenção.
+    pub fn degree(&self, v: &Vertice) -> usize {
+        let total = v.in_degree() + v.out_degree();
+        match self {
+            DegreeConvention::LoopCountsTwice => total,
+            DegreeConvention::LoopCountsOnce => {
+                total - v.get_edges_to(v.key()).map_or(0, |loops| loops.len())
+            }
+        }
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Grau não direcionado de um vértice (`in_degree + out_degree`), com laços contados
+    /// segundo `convention`. Arestas paralelas contam separadamente.
+    ///
+    /// `None` se o vértice não existir. A clusterização e a assortatividade usam vizinhos
+    /// distintos sem laços, então não dependem da convenção.
+    pub fn degree(&self, key: i32, convention: DegreeConvention) -> Option<usize> {
+        Some(convention.degree(self.get_vertice_arc(key)?))
+    }
+
+    /// Histograma do grau total: o índice `d` guarda quantos vértices têm grau `d`.
+    ///
+    /// O grau total é `in_degree + out_degree` (direção ignorada), então arestas paralelas
+    /// contam separadamente e um laço soma 2 (`DegreeConvention::default()`). O vetor vai até
+    /// o maior grau presente, e é vazio para um grafo sem vértices.
+    pub fn degree_histogram(&self) -> Vec<usize> {
+        self.degree_histogram_with(DegreeConvention::default())
+    }
+
+    /// Histograma do grau total com laços contados segundo `convention`
+    /// (ver `degree_histogram`).
+    pub fn degree_histogram_with(&self, convention: DegreeConvention) -> Vec<usize> {
+        self.histogram_by(|v| convention.degree(v))
+    }
+
+    /// Histograma do grau de saída: o índice `d` guarda quantos vértices têm `d` arestas saindo.
+    pub fn out_degree_histogram(&self) -> Vec<usize> {
+        self.histogram_by(Vertice::out_degree)
+    }
+
+    /// Histograma do grau de entrada: o índice `d` guarda quantos vértices têm `d` arestas chegando.
+    pub fn in_degree_histogram(&self) -> Vec<usize> {
+        self.histogram_by(Vertice::in_degree)
+    }
+
+    /// Força de saída: soma dos pesos das arestas que saem do vértice.
+    /// `None` se o vértice não existir.
+    pub fn out_strength(&self, key: i32) -> Option<i64> {
+        let vertice = self.get_vertice_arc(key)?;
+        Some(vertice.edges_vec_ref().iter().map(|e| e.weight() as i64).sum())
+    }
+
+    /// Força de entrada: soma dos pesos das arestas que chegam no vértice.
+    /// `None` se o vértice não existir.
+    pub fn in_strength(&self, key: i32) -> Option<i64> {
+        let vertice = self.get_vertice_arc(key)?;
+        Some(vertice.back_edges_hashmap().iter().map(|e| e.weight() as i64).sum())
+    }
+
+    /// Vértice com a maior força de saída (`out_strength`); empates ficam com a menor chave.
+    /// `None` se o grafo não tiver vértices.
+    pub fn max_out_strength_vertex(&self) -> Option<i32> {
+        self.max_strength_vertex(|key| self.out_strength(key))
+    }
+
+    /// Vértice com a maior força de entrada (`in_strength`); empates ficam com a menor chave.
+    /// `None` se o grafo não tiver vértices.
+    pub fn max_in_strength_vertex(&self) -> Option<i32> {
+        self.max_strength_vertex(|key| self.in_strength(key))
+    }
+
+    fn max_strength_vertex<F>(&self, strength: F) -> Option<i32>
+    where
+        F: Fn(i32) -> Option<i64>,
+    {
+        self.vertices_sorted()
+            .into_iter()
+            .min_by_key(|&key| (std::cmp::Reverse(strength(key).unwrap()), key))
+    }
+
+    fn histogram_by<F>(&self, degree: F) -> Vec<usize>
+    where
+        F: Fn(&Vertice) -> usize,
+    {
+        let mut histogram = Vec::new();
+        for v in self.iter_vertices() {
+            let d = degree(v);
+            if histogram.len() <= d {
+                histogram.resize(d + 1, 0);
+            }
+            histogram[d] += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_histograms() {
+        // Centro 0 com cinco folhas
+        let g = DiGraph::from_edge_list(&[(0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]);
+        assert_eq!(g.degree_histogram(), vec![0, 5, 0, 0, 0, 1]);
+        assert_eq!(g.out_degree_histogram(), vec![5, 0, 0, 0, 0, 1]);
+        assert_eq!(g.in_degree_histogram(), vec![1, 5]);
+        assert_eq!(
+            g.degree_histogram().iter().sum::<usize>(),
+            g.vertices_length()
+        );
+        assert!(DiGraph::new().degree_histogram().is_empty());
+    }
+
+    #[test]
+    fn strengths_sum_weights_by_direction() {
+        let mut g = DiGraph::from_weighted_edges(&[(0, 1, 4), (0, 1, -1), (2, 0, 6), (0, 0, 2)]);
+        g.add_vertice(5);
+        assert_eq!(g.out_strength(0), Some(5));
+        assert_eq!(g.in_strength(0), Some(8));
+        assert_eq!(g.in_strength(1), Some(3));
+        assert_eq!(g.out_strength(5), Some(0));
+        assert_eq!(g.out_strength(9), None);
+    }
+
+    #[test]
+    fn strength_hubs_with_ties_on_smallest_key() {
+        // 2 recebe 9 no total; 1 e 3 empatam com 6 de saída
+        let g =
+            DiGraph::from_weighted_edges(&[(3, 2, 4), (3, 0, 2), (1, 2, 5), (1, 0, 1), (0, 2, 0)]);
+        assert_eq!(g.max_in_strength_vertex(), Some(2));
+        assert_eq!(g.max_out_strength_vertex(), Some(1));
+
+        // Pesos negativos: o maior ainda vence, mesmo abaixo de zero
+        let negative = DiGraph::from_weighted_edges(&[(5, 6, -3), (7, 6, -1), (6, 5, -2)]);
+        assert_eq!(negative.max_out_strength_vertex(), Some(7));
+        assert_eq!(negative.max_in_strength_vertex(), Some(7));
+        assert_eq!(DiGraph::new().max_out_strength_vertex(), None);
+    }
+
+    #[test]
+    fn self_loops_under_both_conventions() {
+        // 0 tem dois laços, uma aresta saindo e uma chegando
+        let g = DiGraph::from_edge_list(&[(0, 0), (0, 0), (0, 1), (2, 0)]);
+        assert_eq!(g.degree(0, DegreeConvention::LoopCountsTwice), Some(6));
+        assert_eq!(g.degree(0, DegreeConvention::LoopCountsOnce), Some(4));
+        assert_eq!(g.degree(1, DegreeConvention::LoopCountsOnce), Some(1));
+        assert_eq!(g.degree(9, DegreeConvention::default()), None);
+        assert_eq!(
+            DegreeConvention::default(),
+            DegreeConvention::LoopCountsTwice
+        );
+
+        // Com a convenção padrão a soma dos graus é o dobro das arestas
+        let total: usize = g
+            .vertices_sorted()
+            .into_iter()
+            .map(|v| g.degree(v, DegreeConvention::default()).unwrap())
+            .sum();
+        assert_eq!(total, 2 * g.edges_length());
+
+        assert_eq!(g.degree_histogram(), vec![0, 2, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            g.degree_histogram_with(DegreeConvention::LoopCountsOnce),
+            vec![0, 2, 0, 0, 1]
+        );
+    }
+}
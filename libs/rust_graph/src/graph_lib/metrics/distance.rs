@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use crate::{graph_lib::minPath::dijkstra::Dijkstra, tools::inifinity::Infinity, DiGraph};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Excentricidade ponderada de um vértice: a maior distância mínima dele até os demais.
+    ///
+    /// Usa Dijkstra, então os pesos devem ser não negativos.
+    /// Retorna `Infinite` se algum vértice não for alcançável e `None` se o vértice não existir.
+    pub fn weighted_eccentricity(&self, key: i32) -> Option<Infinity> {
+        if !self.vertice_exists(key) {
+            return None;
+        }
+        let data = Dijkstra::shortest_path(self, key);
+        data.dist().values().copied().max()
+    }
+
+    /// Diâmetro ponderado: a maior distância mínima entre quaisquer dois vértices.
+    ///
+    /// Executa Dijkstra a partir de cada vértice.
+    /// Retorna `Some(Infinite)` se algum par de vértices não se alcança
+    /// e `None` se o grafo não tiver vértices.
+    pub fn weighted_diameter(&self) -> Option<Infinity> {
+        self.get_vertice_key_array()
+            .into_iter()
+            .filter_map(|v| self.weighted_eccentricity(v))
+            .max()
+    }
+
+    /// Vértices agrupados pela distância em saltos (BFS, pesos ignorados) a partir de `start`,
+    /// seguindo a direção das arestas.
+    ///
+    /// O índice 0 é `[start]`, o 1 são os sucessores de `start`, e assim por diante; cada
+    /// camada vem em ordem crescente. Vértices inalcançáveis não aparecem. Vazio se `start`
+    /// não existir.
+    pub fn distance_layers(&self, start: i32) -> Vec<Vec<i32>> {
+        if !self.vertice_exists(start) {
+            return Vec::new();
+        }
+        let mut visited: HashSet<i32> = HashSet::from([start]);
+        let mut layers = vec![vec![start]];
+        loop {
+            let mut next: Vec<i32> = Vec::new();
+            for &v in layers.last().unwrap() {
+                for e in self.get_vertice_arc(v).unwrap().edges_vec_ref() {
+                    if visited.insert(e.destiny_key()) {
+                        next.push(e.destiny_key());
+                    }
+                }
+            }
+            if next.is_empty() {
+                return layers;
+            }
+            next.sort();
+            layers.push(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::{edge::Edge, minPath::bellman::find_shortest_path};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn weighted_diameter_is_the_longest_shortest_path() {
+        // Ciclo 0 -> 1 -> 2 -> 0 com atalho 0 -> 2: o par mais distante é (1, 0)
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 2), (1, 2, 3), (2, 0, 4), (0, 2, 10)]);
+        assert_eq!(g.weighted_eccentricity(0), Some(Infinity::Number(5)));
+        assert_eq!(g.weighted_eccentricity(1), Some(Infinity::Number(7)));
+        assert_eq!(g.weighted_diameter(), Some(Infinity::Number(7)));
+        assert_eq!(g.weighted_eccentricity(9), None);
+    }
+
+    #[test]
+    fn weighted_diameter_of_disconnected_and_empty_graphs() {
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 2), (1, 0, 2), (2, 3, 1)]);
+        assert_eq!(g.weighted_diameter(), Some(Infinity::Infinite));
+        assert_eq!(DiGraph::new().weighted_diameter(), None);
+    }
+
+    #[test]
+    fn weighted_diameter_matches_bellman_ford() {
+        let mut rng = StdRng::seed_from_u64(360);
+        for _ in 0..100 {
+            let n = rng.gen_range(1..8);
+            let mut g = DiGraph::new();
+            for v in 0..n {
+                g.add_vertice(v);
+            }
+            for _ in 0..rng.gen_range(0..25) {
+                let (v, w) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(0..10)));
+            }
+            let expected = (0..n)
+                .flat_map(|v| {
+                    find_shortest_path(&g, v)
+                        .pot()
+                        .values()
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+                .max();
+            assert_eq!(g.weighted_diameter(), expected);
+        }
+    }
+
+    #[test]
+    fn distance_layers_follow_direction() {
+        let g = DiGraph::from_edge_list(&[(0, 2), (0, 1), (1, 3), (2, 3), (3, 4), (5, 0)]);
+        assert_eq!(
+            g.distance_layers(0),
+            vec![vec![0], vec![1, 2], vec![3], vec![4]]
+        );
+        // 5 só tem arestas de saída; 4 não alcança ninguém
+        assert_eq!(g.distance_layers(5)[1], vec![0]);
+        assert_eq!(g.distance_layers(4), vec![vec![4]]);
+        assert!(g.distance_layers(9).is_empty());
+    }
+
+    #[test]
+    fn distance_layers_match_unit_weight_dijkstra() {
+        let mut rng = StdRng::seed_from_u64(449);
+        for _ in 0..100 {
+            let mut g = DiGraph::new();
+            g.add_vertice(0);
+            for _ in 0..15 {
+                let (v, w) = (rng.gen_range(0..10), rng.gen_range(0..10));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(0..10)));
+            }
+            let layers = g.distance_layers(0);
+
+            // Com todo peso igual a 1, a distância mínima é o número de saltos
+            let mut unit = g.clone();
+            unit.map_weights(|_| 1);
+            let mut expected: Vec<Vec<i32>> = Vec::new();
+            for (&v, &d) in Dijkstra::shortest_path(&unit, 0).dist() {
+                if let Infinity::Number(d) = d {
+                    let d = d as usize;
+                    if expected.len() <= d {
+                        expected.resize(d + 1, Vec::new());
+                    }
+                    expected[d].push(v);
+                }
+            }
+            for layer in expected.iter_mut() {
+                layer.sort();
+            }
+            assert_eq!(layers, expected);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod clustering;
+pub mod cores;
+pub mod distance;
+pub mod assortativity;
+pub mod betweenness;
+pub mod degree;
+pub mod report;
+pub mod cliques;
+pub mod community;
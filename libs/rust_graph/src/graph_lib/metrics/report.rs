@@ -0,0 +1,98 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::DiGraph;
+
+/// Resumo das métricas de um único vértice (ver `DiGraph::vertex_report`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct VertexReport {
+    pub key: i32,
+    pub out_degree: usize,
+    pub in_degree: usize,
+    /// Soma dos pesos das arestas que saem
+    pub out_strength: i64,
+    /// Soma dos pesos das arestas que chegam
+    pub in_strength: i64,
+    /// Coeficiente de agrupamento local (ver `clustering_coefficient`)
+    pub clustering_coefficient: f64,
+    /// Centralidade de proximidade (ver `DiGraph::vertex_report`)
+    pub closeness: f64,
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Calcula de uma vez as métricas de um único vértice, sem percorrer o grafo inteiro
+    /// para cada uma delas.
+    ///
+    /// A proximidade é `(r - 1) / soma das distâncias`, onde `r` é o número de vértices
+    /// alcançados a partir de `key` (ele incluso) e as distâncias são em saltos seguindo
+    /// a direção das arestas. Vale `0.0` se o vértice não alcança ninguém.
+    ///
+    /// `None` se o vértice não existir.
+    pub fn vertex_report(&self, key: i32) -> Option<VertexReport> {
+        let vertice = self.get_vertice_arc(key)?;
+        Some(VertexReport {
+            key,
+            out_degree: vertice.out_degree(),
+            in_degree: vertice.in_degree(),
+            out_strength: self.out_strength(key)?,
+            in_strength: self.in_strength(key)?,
+            clustering_coefficient: self.clustering_coefficient(key)?,
+            closeness: self.closeness_centrality(key)?,
+        })
+    }
+
+    /// Centralidade de proximidade de um vértice (em saltos, seguindo a direção das arestas).
+    ///
+    /// `None` se o vértice não existir.
+    pub fn closeness_centrality(&self, key: i32) -> Option<f64> {
+        if !self.vertice_exists(key) {
+            return None;
+        }
+        let mut dist: HashMap<i32, usize> = HashMap::from([(key, 0)]);
+        let mut queue = VecDeque::from([key]);
+        while let Some(v) = queue.pop_front() {
+            for w in self.get_sucessor(v).unwrap_or_default() {
+                if !dist.contains_key(&w) {
+                    dist.insert(w, dist[&v] + 1);
+                    queue.push_back(w);
+                }
+            }
+        }
+        let total: usize = dist.values().sum();
+        if total == 0 {
+            return Some(0.0);
+        }
+        Some((dist.len() - 1) as f64 / total as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_of_a_known_vertex() {
+        let g =
+            DiGraph::from_weighted_edges(&[(0, 1, 2), (0, 2, 3), (1, 2, 4), (2, 0, 1), (2, 3, 5)]);
+        let report = g.vertex_report(2).unwrap();
+        assert_eq!(report.key, 2);
+        assert_eq!(report.out_degree, 2);
+        assert_eq!(report.in_degree, 2);
+        assert_eq!(report.out_strength, 6);
+        assert_eq!(report.in_strength, 7);
+        // Vizinhos {0, 1, 3}: só 0 e 1 estão ligados
+        assert!((report.clustering_coefficient - 1.0 / 3.0).abs() < 1e-12);
+        // 0 e 3 a um salto, 1 a dois
+        assert!((report.closeness - 0.75).abs() < 1e-12);
+        assert_eq!(
+            report.clustering_coefficient,
+            g.clustering_coefficient(2).unwrap()
+        );
+
+        // 3 não alcança ninguém
+        let sink = g.vertex_report(3).unwrap();
+        assert_eq!((sink.out_degree, sink.in_strength), (0, 5));
+        assert_eq!(sink.closeness, 0.0);
+        assert_eq!(g.vertex_report(9), None);
+    }
+}
@@ -1,10 +1,15 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
 
+use super::dijkstra::Dijkstra;
 use crate::{tools::inifinity::Infinity, DiGraph};
 
 pub struct Bellman {
     pred: HashMap<i32, i32>,
     pot: HashMap<i32, Infinity>,
+    negative_cycle: bool,
 }
 
 #[allow(unused)]
@@ -13,6 +18,7 @@ impl Bellman {
         Bellman {
             pred: HashMap::new(),
             pot: HashMap::new(),
+            negative_cycle: false,
         }
     }
 
@@ -22,6 +28,12 @@ impl Bellman {
     pub fn pot(&self) -> &HashMap<i32, Infinity> {
         &self.pot
     }
+
+    /// `true` se a busca encontrou um ciclo negativo alcançável a partir da origem.
+    /// Nesse caso os potenciais não são distâncias mínimas.
+    pub fn has_negative_cycle(&self) -> bool {
+        self.negative_cycle
+    }
 }
 use Infinity::*;
 
@@ -36,11 +48,16 @@ pub fn find_shortest_path(graph: &DiGraph, start: i32) -> Bellman {
     }
     
     data.pot.insert(start, Number(0));
-    for _ in 0..graph.vertices_length() {
+    let vertices = graph.vertices_sorted();
+    for round in 0..graph.vertices_length() {
         let mut change = false;
-        for v in graph.iter_vertices() {
-         
-            for e in v.edges_vec_ref() {
+        for &v_key in vertices.iter() {
+            let v_d = *data.pot.get(&v_key).unwrap();
+            if v_d.is_infinite() {
+                continue; // Origem ainda inalcançável: nenhuma aresta dela pode relaxar
+            }
+            let v = graph.get_vertice_arc(v_key).unwrap();
+            for e in v.edges_sorted().iter() {
                 let w = e.destiny_key();
                 let v = v.key();
                 let v_d = *data.pot.get(&v).unwrap();
@@ -56,6 +73,506 @@ pub fn find_shortest_path(graph: &DiGraph, start: i32) -> Bellman {
         if !change {
             break;
         }
+        // Ainda houve relaxamento na V-ésima rodada
+        data.negative_cycle = round + 1 == graph.vertices_length();
+    }
+    data
+}
+
+/// Vértices de um ciclo negativo alcançável a partir de `start`, na ordem das arestas
+/// (`c[0] -> c[1] -> ... -> c[0]`) e começando pela menor chave.
+///
+/// Depois do Bellman-Ford, se alguma aresta ainda relaxa na rodada extra, segue os predecessores
+/// `V` vezes a partir do vértice melhorado para cair dentro do ciclo. `None` se não houver
+/// ciclo negativo alcançável (ou se `start` não existir).
+#[allow(unused)]
+pub fn negative_cycle_reachable_from(graph: &DiGraph, start: i32) -> Option<Vec<i32>> {
+    if !graph.vertice_exists(start) {
+        return None;
+    }
+    let mut data = find_shortest_path(graph, start);
+    if !data.has_negative_cycle() {
+        return None;
+    }
+
+    // Rodada extra: o primeiro relaxamento encontrado aponta para um vértice afetado pelo ciclo
+    let mut improved = None;
+    for v_key in graph.vertices_sorted() {
+        let v_d = data.pot[&v_key];
+        if v_d.is_infinite() {
+            continue;
+        }
+        for e in graph.get_vertice_arc(v_key).unwrap().edges_sorted() {
+            let w = e.destiny_key();
+            if data.pot[&w] > v_d + Number(e.weight()) {
+                data.pred.insert(w, v_key);
+                improved = Some(w);
+                break;
+            }
+        }
+        if improved.is_some() {
+            break;
+        }
+    }
+
+    let mut v = improved?;
+    for _ in 0..graph.vertices_length() {
+        v = data.pred[&v];
+    }
+    let mut cycle = vec![v];
+    let mut u = data.pred[&v];
+    while u != v {
+        cycle.push(u);
+        u = data.pred[&u];
+    }
+    cycle.reverse();
+    let min_pos = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+    cycle.rotate_left(min_pos);
+    Some(cycle)
+}
+
+/// Bellman-Ford com fila (SPFA).
+///
+/// Só reexamina os vértices cuja distância mudou, o que costuma ser bem mais rápido que
+/// `find_shortest_path` em grafos esparsos. Produz os mesmos potenciais que `find_shortest_path`
+/// (os predecessores podem diferir em caso de empate).
+///
+/// Um caminho melhorado com `V` arestas ou mais indica ciclo negativo: a busca para e
+/// `Bellman::has_negative_cycle` passa a retornar `true`. (Contar relaxamentos não serve:
+/// arestas paralelas relaxam o mesmo vértice várias vezes na mesma varredura.)
+#[allow(unused)]
+pub fn spfa(graph: &DiGraph, start: i32) -> Bellman {
+    let mut data = Bellman::new();
+
+    for v in graph.iter_vertices() {
+        data.pot.insert(v.key(), Infinite);
+        data.pred.insert(v.key(), -1);
+    }
+    if !graph.vertice_exists(start) {
+        return data;
+    }
+    data.pot.insert(start, Number(0));
+
+    let len = graph.vertices_length();
+    // Quantidade de arestas do melhor caminho conhecido até cada vértice
+    let mut path_len: HashMap<i32, usize> = HashMap::from([(start, 0)]);
+    let mut in_queue: HashMap<i32, bool> = HashMap::with_capacity(len);
+    let mut queue = VecDeque::from([start]);
+    in_queue.insert(start, true);
+
+    while let Some(v) = queue.pop_front() {
+        in_queue.insert(v, false);
+        let v_d = data.pot[&v];
+        for e in graph.get_vertice_arc(v).unwrap().edges_sorted() {
+            let w = e.destiny_key();
+            if data.pot[&w] > v_d + Number(e.weight()) {
+                data.pot.insert(w, Number(v_d.unwrap() + e.weight()));
+                data.pred.insert(w, v);
+
+                let w_len = path_len[&v] + 1;
+                path_len.insert(w, w_len);
+                if w_len >= len {
+                    data.negative_cycle = true;
+                    return data;
+                }
+                if !in_queue.get(&w).copied().unwrap_or(false) {
+                    in_queue.insert(w, true);
+                    queue.push_back(w);
+                }
+            }
+        }
     }
     data
 }
+
+#[allow(unused)]
+impl DiGraph {
+    /// Menores distâncias a partir de `start` usando no máximo `k` arestas.
+    ///
+    /// Executa exatamente `k` rodadas de relaxamento de Bellman-Ford. Cada rodada
+    /// relaxa a partir das distâncias da rodada anterior, então um caminho nunca ganha
+    /// mais de uma aresta por rodada.
+    ///
+    /// Diferente de `find_shortest_path`, vértices que só são alcançáveis com mais de `k`
+    /// arestas ficam com `Infinite`, e um caminho mais barato com muitas arestas pode ser ignorado.
+    pub fn shortest_path_k_hops(&self, start: i32, k: usize) -> HashMap<i32, Infinity> {
+        let mut pot: HashMap<i32, Infinity> = self
+            .get_vertice_key_array()
+            .into_iter()
+            .map(|v| (v, Infinite))
+            .collect();
+        if !self.vertice_exists(start) {
+            return pot;
+        }
+        pot.insert(start, Number(0));
+
+        let edges = self.edges_sorted();
+        for _ in 0..k {
+            let previous = pot.clone();
+            let mut change = false;
+            for e in edges.iter() {
+                let (v, w) = e.v_w();
+                let candidate = previous[&v] + Number(e.weight());
+                if pot[&w] > candidate {
+                    pot.insert(w, candidate);
+                    change = true;
+                }
+            }
+            if !change {
+                break;
+            }
+        }
+        pot
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Executa Bellman-Ford em cada componente fracamente conexo do grafo.
+    ///
+    /// O representante de cada componente é o seu menor vértice, e a busca roda apenas
+    /// sobre o subgrafo induzido pelo componente, então o resultado contém só os vértices dele.
+    /// Vértices do componente que o representante não alcança (por causa da direção) ficam `Infinite`.
+    ///
+    /// Retorna um mapa do representante para o seu resultado.
+    pub fn all_components_shortest_paths(&self) -> HashMap<i32, Bellman> {
+        self.weakly_connected_components()
+            .into_iter()
+            .map(|component| {
+                let representative = component[0];
+                let subgraph = self.induced_subgraph(&component);
+                (representative, find_shortest_path(&subgraph, representative))
+            })
+            .collect()
+    }
+}
+
+/// Erro de `DiGraph::shortest_paths`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortestPathError {
+    /// O vértice de origem não existe
+    VertexNotFound(i32),
+    /// Há um ciclo negativo alcançável a partir da origem
+    NegativeCycle,
+}
+
+impl fmt::Display for ShortestPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortestPathError::VertexNotFound(key) => write!(f, "vértice {} não existe", key),
+            ShortestPathError::NegativeCycle => write!(f, "ciclo negativo alcançável"),
+        }
+    }
+}
+
+impl std::error::Error for ShortestPathError {}
+
+/// Algoritmo escolhido por `DiGraph::shortest_paths`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortestPathAlgorithm {
+    Dijkstra,
+    BellmanFord,
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Algoritmo que `shortest_paths` usaria: Dijkstra se nenhum peso for negativo,
+    /// Bellman-Ford caso contrário.
+    pub fn shortest_path_algorithm(&self) -> ShortestPathAlgorithm {
+        if self.has_negative_weights() {
+            ShortestPathAlgorithm::BellmanFord
+        } else {
+            ShortestPathAlgorithm::Dijkstra
+        }
+    }
+
+    /// Caminhos mínimos a partir de `start`, escolhendo o algoritmo pelos pesos
+    /// (ver `shortest_path_algorithm`).
+    ///
+    /// O resultado tem sempre o formato de `Bellman`: todo vértice aparece em `pot`
+    /// (`Infinite` se inalcançável) e em `pred` (`-1` sem predecessor).
+    ///
+    /// `ShortestPathError` se `start` não existir ou se houver ciclo negativo alcançável.
+    pub fn shortest_paths(&self, start: i32) -> Result<Bellman, ShortestPathError> {
+        if !self.vertice_exists(start) {
+            return Err(ShortestPathError::VertexNotFound(start));
+        }
+        match self.shortest_path_algorithm() {
+            ShortestPathAlgorithm::Dijkstra => {
+                let data = Dijkstra::shortest_path(self, start);
+                Ok(Bellman {
+                    pred: data.pred().clone(),
+                    pot: data.dist().clone(),
+                    negative_cycle: false,
+                })
+            }
+            ShortestPathAlgorithm::BellmanFord => {
+                let data = find_shortest_path(self, start);
+                if data.has_negative_cycle() {
+                    return Err(ShortestPathError::NegativeCycle);
+                }
+                Ok(data)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+
+    fn random_graph(rng: &mut StdRng, vertices: i32, edges: usize, min_weight: i32) -> DiGraph {
+        let mut g = DiGraph::new();
+        for v in 0..vertices {
+            g.add_vertice(v);
+        }
+        for _ in 0..edges {
+            let (v, w) = (rng.gen_range(0..vertices), rng.gen_range(0..vertices));
+            g.add_edge(Edge::new_weighted(v, w, rng.gen_range(min_weight..10)));
+        }
+        g
+    }
+
+    #[test]
+    fn spfa_parallel_edges_are_not_a_negative_cycle() {
+        let mut g = DiGraph::new();
+        g.add_vertice(2);
+        for weight in [9, 5, 1] {
+            g.add_edge(Edge::new_weighted(0, 1, weight));
+        }
+        let data = spfa(&g, 0);
+        assert!(!data.has_negative_cycle());
+        assert_eq!(data.pot()[&1], Number(1));
+        assert_eq!(data.pot()[&2], Infinite);
+    }
+
+    #[test]
+    fn spfa_matches_bellman_ford() {
+        let mut rng = StdRng::seed_from_u64(385);
+        for _ in 0..2000 {
+            let g = random_graph(&mut rng, 6, 12, -3);
+            let bellman = find_shortest_path(&g, 0);
+            let queue = spfa(&g, 0);
+            assert_eq!(bellman.has_negative_cycle(), queue.has_negative_cycle());
+            if !bellman.has_negative_cycle() {
+                assert_eq!(bellman.pot(), queue.pot());
+            }
+        }
+    }
+
+    #[test]
+    fn k_hops_leaves_far_vertices_infinite() {
+        // 3 só é alcançável com 3 arestas; 4 tem um atalho caro de 1 aresta
+        let g =
+            DiGraph::from_weighted_edges(&[(0, 1, 1), (1, 2, 1), (2, 3, 1), (2, 4, 1), (0, 4, 10)]);
+        let two = g.shortest_path_k_hops(0, 2);
+        assert_eq!(two[&2], Number(2));
+        assert_eq!(two[&3], Infinite);
+        assert_eq!(two[&4], Number(10));
+
+        let three = g.shortest_path_k_hops(0, 3);
+        assert_eq!(three[&3], Number(3));
+        assert_eq!(three[&4], Number(3));
+
+        let zero = g.shortest_path_k_hops(0, 0);
+        assert_eq!(zero[&0], Number(0));
+        assert_eq!(zero[&1], Infinite);
+    }
+
+    #[test]
+    fn k_hops_with_enough_rounds_matches_bellman_ford() {
+        let mut rng = StdRng::seed_from_u64(364);
+        for _ in 0..500 {
+            let g = random_graph(&mut rng, 6, 12, -3);
+            let bellman = find_shortest_path(&g, 0);
+            if !bellman.has_negative_cycle() {
+                assert_eq!(&g.shortest_path_k_hops(0, 5), bellman.pot());
+            }
+        }
+    }
+
+    #[test]
+    fn all_components_shortest_paths_per_component() {
+        // Componente {1, 2, 3} com 3 -> 1, e componente {4, 5, 6}
+        let mut g =
+            DiGraph::from_weighted_edges(&[(1, 2, 4), (2, 3, -1), (3, 1, 2), (5, 4, 7), (5, 6, 1)]);
+        g.add_vertice(8);
+        let results = g.all_components_shortest_paths();
+
+        let mut representatives: Vec<i32> = results.keys().copied().collect();
+        representatives.sort();
+        assert_eq!(representatives, vec![1, 4, 8]);
+
+        let first = results[&1].pot();
+        assert_eq!(first.len(), 3);
+        assert_eq!(first[&2], Number(4));
+        assert_eq!(first[&3], Number(3));
+
+        // 4 não alcança 5 e 6 por causa da direção
+        let second = results[&4].pot();
+        assert_eq!(second.len(), 3);
+        assert_eq!(second[&4], Number(0));
+        assert_eq!(second[&5], Infinite);
+        assert_eq!(second[&6], Infinite);
+
+        assert_eq!(results[&8].pot().len(), 1);
+    }
+
+    /// Bellman-Ford sem a poda: relaxa todas as arestas em todas as rodadas
+    fn unpruned_bellman(
+        g: &DiGraph,
+        start: i32,
+    ) -> (HashMap<i32, Infinity>, HashMap<i32, i32>, bool) {
+        let mut pot: HashMap<i32, Infinity> = g
+            .vertices_sorted()
+            .into_iter()
+            .map(|v| (v, Infinite))
+            .collect();
+        let mut pred: HashMap<i32, i32> = pot.keys().map(|&v| (v, -1)).collect();
+        pot.insert(start, Number(0));
+        let mut negative_cycle = false;
+        for round in 0..g.vertices_length() {
+            let mut change = false;
+            for e in g.edges_sorted() {
+                let (v, w) = e.v_w();
+                if pot[&w] > pot[&v] + Number(e.weight()) {
+                    pot.insert(w, pot[&v] + Number(e.weight()));
+                    pred.insert(w, v);
+                    change = true;
+                }
+            }
+            if !change {
+                break;
+            }
+            negative_cycle = round + 1 == g.vertices_length();
+        }
+        (pot, pred, negative_cycle)
+    }
+
+    #[test]
+    fn pruning_infinite_origins_keeps_the_result() {
+        let mut rng = StdRng::seed_from_u64(407);
+        for _ in 0..1000 {
+            let g = random_graph(&mut rng, 7, 10, -2);
+            let start = rng.gen_range(0..7);
+            let data = find_shortest_path(&g, start);
+            let (pot, pred, negative_cycle) = unpruned_bellman(&g, start);
+            assert_eq!(data.has_negative_cycle(), negative_cycle);
+            assert_eq!(data.pot(), &pot);
+            assert_eq!(data.pred(), &pred);
+        }
+    }
+
+    #[test]
+    fn far_away_source_on_a_reversed_chain() {
+        // Origem 99 e cadeia 99 -> 98 -> ... -> 0: em ordem crescente cada rodada só
+        // alcança um vértice novo, e as arestas de origens ainda infinitas são puladas
+        let chain: Vec<(i32, i32, i32)> = (1..100).map(|v| (v, v - 1, 2)).collect();
+        let g = DiGraph::from_weighted_edges(&chain);
+        let data = find_shortest_path(&g, 99);
+        assert!(!data.has_negative_cycle());
+        assert_eq!(data.pot()[&0], Number(198));
+        assert_eq!(data.pred()[&0], 1);
+        assert_eq!(data.pot(), &unpruned_bellman(&g, 99).0);
+    }
+
+    #[test]
+    fn shortest_paths_picks_the_algorithm_by_weights() {
+        let mut rng = StdRng::seed_from_u64(425);
+        for _ in 0..500 {
+            let non_negative = random_graph(&mut rng, 6, 12, 0);
+            assert_eq!(
+                non_negative.shortest_path_algorithm(),
+                ShortestPathAlgorithm::Dijkstra
+            );
+            let data = non_negative.shortest_paths(0).unwrap();
+            assert_eq!(data.pot(), Dijkstra::shortest_path(&non_negative, 0).dist());
+            assert_eq!(data.pot(), find_shortest_path(&non_negative, 0).pot());
+            assert_eq!(data.pred().len(), non_negative.vertices_length());
+
+            let mut negative = random_graph(&mut rng, 6, 12, -3);
+            negative.add_edge(Edge::new_weighted(5, 4, -1));
+            assert_eq!(
+                negative.shortest_path_algorithm(),
+                ShortestPathAlgorithm::BellmanFord
+            );
+            let direct = find_shortest_path(&negative, 0);
+            match negative.shortest_paths(0) {
+                Ok(data) => {
+                    assert!(!direct.has_negative_cycle());
+                    assert_eq!(data.pot(), direct.pot());
+                    assert_eq!(data.pred(), direct.pred());
+                }
+                Err(err) => {
+                    assert_eq!(err, ShortestPathError::NegativeCycle);
+                    assert!(direct.has_negative_cycle());
+                }
+            }
+        }
+        assert_eq!(
+            DiGraph::new().shortest_paths(3).err(),
+            Some(ShortestPathError::VertexNotFound(3))
+        );
+    }
+
+    /// Menor peso total do ciclo `c[0] -> c[1] -> ... -> c[0]`, ou `None` se faltar alguma aresta
+    fn cycle_weight(g: &DiGraph, cycle: &[i32]) -> Option<i64> {
+        (0..cycle.len())
+            .map(|i| {
+                let (v, w) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+                g.edge_weights_between(v, w).into_iter().min()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn negative_cycle_on_a_known_graph() {
+        // 0 -> 1 -> {2 -> 3 -> 1} com peso total -1; 4 -> 5 -> 4 é negativo mas inalcançável
+        let g = DiGraph::from_weighted_edges(&[
+            (0, 1, 4),
+            (1, 2, 2),
+            (2, 3, 1),
+            (3, 1, -4),
+            (3, 6, 1),
+            (4, 5, -2),
+            (5, 4, -2),
+        ]);
+        assert_eq!(negative_cycle_reachable_from(&g, 0), Some(vec![1, 2, 3]));
+        assert_eq!(negative_cycle_reachable_from(&g, 4), Some(vec![4, 5]));
+        assert_eq!(negative_cycle_reachable_from(&g, 6), None);
+        assert_eq!(negative_cycle_reachable_from(&g, 99), None);
+
+        // Laço negativo
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 2), (1, 1, -1)]);
+        assert_eq!(negative_cycle_reachable_from(&g, 0), Some(vec![1]));
+    }
+
+    #[test]
+    fn negative_cycle_is_a_reachable_negative_cycle() {
+        let mut rng = StdRng::seed_from_u64(438);
+        let mut found = 0;
+        for _ in 0..300 {
+            let g = random_graph(&mut rng, 7, 10, -5);
+            let has_cycle = find_shortest_path(&g, 0).has_negative_cycle();
+            let cycle = negative_cycle_reachable_from(&g, 0);
+            assert_eq!(cycle.is_some(), has_cycle);
+            let Some(cycle) = cycle else {
+                continue;
+            };
+            found += 1;
+
+            // Vértices distintos, começando pela menor chave, alcançáveis a partir de 0
+            let mut distinct = cycle.clone();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(distinct.len(), cycle.len());
+            assert_eq!(cycle[0], distinct[0]);
+            assert!(cycle.iter().all(|&v| g.reaches(0, v)));
+            assert!(cycle_weight(&g, &cycle).unwrap() < 0);
+        }
+        assert!(found > 0);
+    }
+}
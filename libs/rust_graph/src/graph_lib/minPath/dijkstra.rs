@@ -1,9 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
+    graph_lib::edge::Edge,
     graph_lib::graph,
+    graph_lib::graph_view::view_distances,
     graph_lib::vertice,
-    tools::{heap::HeapMin, inifinity::Infinity},
+    tools::{
+        heap::HeapMin,
+        inifinity::{Infinity, InfinityF64},
+    },
     DiGraph,
 };
 
@@ -51,19 +56,26 @@ impl Dijkstra {
         &self.dist
     }
 
+    /// Distâncias no mesmo formato de `Bellman::pot`.
+    ///
+    /// Todo vértice do grafo está presente; os inalcançáveis valem `Infinite`.
+    pub fn pot(&self) -> &HashMap<i32, Infinity> {
+        &self.dist
+    }
+
     pub fn shortest_path(g: &DiGraph, v_key: i32) -> Self {
         let mut data = Dijkstra::new_sized(g.vertices_length() as usize);
         let mut queue = HeapMin::new(|a: &VerticeDist, b: &VerticeDist| a.dist().cmp(&b.dist()));
-        data.dist.insert(v_key, Infinity::new(0));
-        queue.insert((VerticeDist(v_key, Infinity::new(0))));
 
+        // Todos os vértices começam inalcançáveis, como em `find_shortest_path`
         for v in g.iter_vertices() {
-            if v.key() != v_key {
-                data.dist.insert(v.key(), Infinite);
-                data.pred.insert(v.key(), -1);
-            }
+            data.dist.insert(v.key(), Infinite);
+            data.pred.insert(v.key(), -1);
         }
 
+        data.dist.insert(v_key, Infinity::new(0));
+        queue.insert(VerticeDist(v_key, Infinity::new(0)));
+
         while !queue.empty() {
             let v = queue.pop().unwrap().0;
             let vertice = g.get_vertice_arc(v);
@@ -77,14 +89,6 @@ impl Dijkstra {
                 let v_d = *data.dist.get(&v.key()).unwrap();
                 let w_d = *data.dist.get(&w).unwrap();
                 if w_d > (v_d + Number(e.weight())) {
-                    println!(
-                        "Relaxando d[{}] = {} com d[{}] = {} + w(v,w) = {}",
-                        w,
-                        w_d,
-                        v.key(),
-                        v_d,
-                        e.weight()
-                    );
                     data.dist.insert(w, Number(v_d.unwrap() + e.weight()));
                     data.pred.insert(w, v.key());
                     queue.insert(VerticeDist(w, Number(v_d.unwrap() + e.weight())));
@@ -94,3 +98,466 @@ impl Dijkstra {
         data
     }
 }
+
+#[allow(unused)]
+impl DiGraph {
+    /// Distâncias mínimas entre cada par ordenado de terminais distintos.
+    ///
+    /// Executa Dijkstra uma vez a partir de cada terminal e guarda apenas as distâncias
+    /// até os outros terminais. Pares inalcançáveis valem `Infinite`; terminais que não
+    /// existem no grafo são ignorados.
+    ///
+    /// Pré-processamento usado pelas heurísticas de árvore de Steiner.
+    pub fn pairwise_shortest_paths(&self, terminals: &[i32]) -> HashMap<(i32, i32), Infinity> {
+        let terminals: Vec<i32> = terminals
+            .iter()
+            .copied()
+            .filter(|&t| self.vertice_exists(t))
+            .collect();
+        let mut distances = HashMap::with_capacity(terminals.len() * terminals.len());
+        for &source in terminals.iter() {
+            let data = Dijkstra::shortest_path(self, source);
+            for &target in terminals.iter() {
+                if target != source {
+                    distances.insert((source, target), data.dist()[&target]);
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Caminho mínimo (Dijkstra) de `source` até `target` e o seu custo.
+    ///
+    /// A busca para assim que `target` é fixado, sem calcular as distâncias para o resto
+    /// do grafo. Os pesos devem ser não negativos.
+    ///
+    /// `None` se `target` for inalcançável ou se algum dos vértices não existir.
+    pub fn dijkstra_path(&self, source: i32, target: i32) -> Option<(Vec<i32>, i64)> {
+        self.shortest_path_avoiding(source, target, &[])
+    }
+
+    /// Caminho mínimo (Dijkstra) de `from` até `to` sem passar pelos vértices de `forbidden`.
+    ///
+    /// Os vértices proibidos apenas deixam de ser explorados; o grafo não é alterado.
+    /// Os pesos devem ser não negativos.
+    ///
+    /// Retorna os vértices do caminho e o seu custo, ou `None` se `to` for inalcançável,
+    /// se algum vértice não existir ou se a origem ou o destino forem proibidos.
+    pub fn shortest_path_avoiding(
+        &self,
+        from: i32,
+        to: i32,
+        forbidden: &[i32],
+    ) -> Option<(Vec<i32>, i64)> {
+        let forbidden: HashSet<i32> = forbidden.iter().copied().collect();
+        if forbidden.contains(&from) || forbidden.contains(&to) {
+            return None;
+        }
+        if !self.vertice_exists(from) || !self.vertice_exists(to) {
+            return None;
+        }
+
+        let mut dist: HashMap<i32, i64> = HashMap::from([(from, 0)]);
+        let mut pred: HashMap<i32, i32> = HashMap::new();
+        let mut queue = HeapMin::new(|a: &(i64, i32), b: &(i64, i32)| a.cmp(b));
+        queue.insert((0, from));
+
+        while let Some((d, v)) = queue.pop() {
+            if d > dist[&v] {
+                continue;
+            }
+            if v == to {
+                break;
+            }
+            for e in self.get_vertice_arc(v).unwrap().edges_vec_ref() {
+                let w = e.destiny_key();
+                if forbidden.contains(&w) {
+                    continue;
+                }
+                let candidate = d + e.weight() as i64;
+                if dist.get(&w).is_none_or(|&current| candidate < current) {
+                    dist.insert(w, candidate);
+                    pred.insert(w, v);
+                    queue.insert((candidate, w));
+                }
+            }
+        }
+
+        let cost = *dist.get(&to)?;
+        let mut path = vec![to];
+        let mut current = to;
+        while let Some(&p) = pred.get(&current) {
+            path.push(p);
+            current = p;
+        }
+        path.reverse();
+        Some((path, cost))
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Sub-DAG de caminhos mínimos a partir de `source`.
+    ///
+    /// Mantém a aresta `u -> v` se `dist[u] + w(u, v) == dist[v]`, isto é, se ela está em
+    /// algum caminho mínimo. Todo caminho de `source` até um vértice nesse grafo é mínimo, e
+    /// todos os caminhos mínimos aparecem. Contém apenas os vértices alcançáveis; as arestas
+    /// mantêm id e peso.
+    ///
+    /// Os pesos devem ser não negativos (Dijkstra). Com pesos positivos o resultado é acíclico;
+    /// ciclos de peso zero podem sobreviver. Grafo vazio se `source` não existir.
+    pub fn shortest_path_dag(&self, source: i32) -> DiGraph {
+        let mut dag = DiGraph::new();
+        if !self.vertice_exists(source) {
+            return dag;
+        }
+        let data = Dijkstra::shortest_path(self, source);
+        let dist = data.dist();
+
+        for v in self.vertices_sorted() {
+            if !dist[&v].is_infinite() {
+                dag.add_vertice(v);
+            }
+        }
+        for e in self.edges_sorted() {
+            let (u, v) = e.v_w();
+            if let (Number(du), Number(dv)) = (dist[&u], dist[&v]) {
+                if du as i64 + e.weight() as i64 == dv as i64 {
+                    dag.add_edge(e);
+                }
+            }
+        }
+        dag
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Distâncias mínimas (Dijkstra) a partir de `source` com pesos `f64`.
+    ///
+    /// `weight` dá o peso de cada aresta (por exemplo, a partir de um atributo `Float`);
+    /// os pesos devem ser não negativos. Uma aresta de peso `NaN` ou `+∞` é ignorada.
+    ///
+    /// Todo vértice do grafo está presente; os inalcançáveis valem `Infinite`.
+    /// Vazio se `source` não existir.
+    pub fn dijkstra_f64<F>(&self, source: i32, weight: F) -> HashMap<i32, InfinityF64>
+    where
+        F: Fn(&Edge) -> f64,
+    {
+        let mut dist: HashMap<i32, InfinityF64> = HashMap::with_capacity(self.vertices_length());
+        if !self.vertice_exists(source) {
+            return dist;
+        }
+        for v in self.iter_vertices() {
+            dist.insert(v.key(), InfinityF64::Infinite);
+        }
+        dist.insert(source, InfinityF64::new(0.0));
+        let mut queue = HeapMin::new(|a: &(InfinityF64, i32), b: &(InfinityF64, i32)| a.cmp(b));
+        queue.insert((InfinityF64::new(0.0), source));
+
+        while let Some((d, v)) = queue.pop() {
+            if d > dist[&v] {
+                continue;
+            }
+            for e in self.get_vertice_arc(v).unwrap().edges_vec_ref() {
+                let w = e.destiny_key();
+                let candidate = d + InfinityF64::new(weight(e));
+                if candidate < dist[&w] {
+                    dist.insert(w, candidate);
+                    queue.insert((candidate, w));
+                }
+            }
+        }
+        dist
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Vértices que estão em pelo menos um caminho mínimo de `from` até `to`, em ordem crescente.
+    ///
+    /// Cruza as distâncias a partir de `from` com as distâncias até `to` (Dijkstra sobre a
+    /// `TransposeView`): `v` entra se `dist_from[v] + dist_to[v] == dist_from[to]`.
+    /// Os pesos devem ser não negativos.
+    ///
+    /// Vazio se `to` for inalcançável ou se algum dos vértices não existir.
+    pub fn vertices_on_shortest_paths(&self, from: i32, to: i32) -> Vec<i32> {
+        let dist_from = view_distances(self, from);
+        let Some(&total) = dist_from.get(&to) else {
+            return vec![];
+        };
+        let dist_to = view_distances(&self.transpose_view(), to);
+
+        let mut corridor: Vec<i32> = dist_from
+            .iter()
+            .filter(|(v, &d)| dist_to.get(v).is_some_and(|&back| d + back == total))
+            .map(|(&v, _)| v)
+            .collect();
+        corridor.sort();
+        corridor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::minPath::bellman::find_shortest_path;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use Infinity::{Infinite, Number};
+
+    fn random_graph(rng: &mut StdRng, vertices: i32, edges: usize) -> DiGraph {
+        let mut g = DiGraph::new();
+        for v in 0..vertices {
+            g.add_vertice(v);
+        }
+        for _ in 0..edges {
+            let (v, w) = (rng.gen_range(0..vertices), rng.gen_range(0..vertices));
+            g.add_edge(Edge::new_weighted(v, w, rng.gen_range(0..10)));
+        }
+        g
+    }
+
+    #[test]
+    fn unreachable_vertices_are_infinite() {
+        let mut g = DiGraph::from_weighted_edges(&[(0, 1, 4), (1, 2, 1), (3, 0, 1)]);
+        g.add_vertice(5);
+        let data = Dijkstra::shortest_path(&g, 0);
+        let pot = data.pot();
+        assert_eq!(pot.len(), 5);
+        assert_eq!(pot[&0], Number(0));
+        assert_eq!(pot[&2], Number(5));
+        assert_eq!(pot[&3], Infinite);
+        assert_eq!(pot[&5], Infinite);
+    }
+
+    #[test]
+    fn pot_matches_bellman_ford() {
+        let mut rng = StdRng::seed_from_u64(367);
+        for _ in 0..300 {
+            let g = random_graph(&mut rng, 7, 14);
+            assert_eq!(
+                Dijkstra::shortest_path(&g, 0).pot(),
+                find_shortest_path(&g, 0).pot()
+            );
+        }
+    }
+
+    #[test]
+    fn pairwise_distances_between_three_terminals() {
+        // Terminais 1, 4 e 6; 6 só recebe arestas
+        let g = DiGraph::from_weighted_edges(&[
+            (1, 2, 2),
+            (2, 4, 3),
+            (1, 4, 9),
+            (4, 1, 1),
+            (4, 5, 1),
+            (5, 6, 4),
+            (2, 6, 20),
+        ]);
+        let distances = g.pairwise_shortest_paths(&[1, 4, 6, 99]);
+        let expected = HashMap::from([
+            ((1, 4), Number(5)),
+            ((1, 6), Number(10)),
+            ((4, 1), Number(1)),
+            ((4, 6), Number(5)),
+            ((6, 1), Infinite),
+            ((6, 4), Infinite),
+        ]);
+        assert_eq!(distances, expected);
+    }
+
+    #[test]
+    fn blocked_vertex_forces_a_detour() {
+        let g =
+            DiGraph::from_weighted_edges(&[(0, 1, 1), (1, 3, 1), (0, 2, 3), (2, 4, 3), (4, 3, 3)]);
+        assert_eq!(
+            g.shortest_path_avoiding(0, 3, &[]),
+            Some((vec![0, 1, 3], 2))
+        );
+        assert_eq!(
+            g.shortest_path_avoiding(0, 3, &[1]),
+            Some((vec![0, 2, 4, 3], 9))
+        );
+        assert_eq!(g.shortest_path_avoiding(0, 3, &[1, 4]), None);
+        assert_eq!(g.shortest_path_avoiding(0, 3, &[0]), None);
+        assert_eq!(g.shortest_path_avoiding(0, 3, &[3]), None);
+        assert_eq!(g.shortest_path_avoiding(0, 0, &[]), Some((vec![0], 0)));
+    }
+
+    #[test]
+    fn avoiding_matches_dijkstra_on_the_reduced_graph() {
+        let mut rng = StdRng::seed_from_u64(395);
+        for _ in 0..300 {
+            let g = random_graph(&mut rng, 8, 20);
+            let forbidden: Vec<i32> = (2..8).filter(|_| rng.gen_bool(0.3)).collect();
+            let allowed: Vec<i32> = (0..8).filter(|v| !forbidden.contains(v)).collect();
+            let reduced = g.induced_subgraph(&allowed);
+
+            let found = g.shortest_path_avoiding(0, 1, &forbidden);
+            let expected = Dijkstra::shortest_path(&reduced, 0).pot()[&1];
+            match found {
+                Some((path, cost)) => {
+                    assert_eq!(Number(cost as i32), expected);
+                    assert!(path.iter().all(|v| !forbidden.contains(v)));
+                    let total: i64 = path
+                        .windows(2)
+                        .map(|p| {
+                            g.edge_weights_between(p[0], p[1])
+                                .into_iter()
+                                .min()
+                                .unwrap()
+                        })
+                        .sum();
+                    assert_eq!(total, cost);
+                }
+                None => assert_eq!(expected, Infinite),
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_path_matches_full_dijkstra() {
+        let mut rng = StdRng::seed_from_u64(408);
+        for _ in 0..300 {
+            let g = random_graph(&mut rng, 8, 16);
+            let full = Dijkstra::shortest_path(&g, 0);
+            for target in 0..8 {
+                match (g.dijkstra_path(0, target), full.dist()[&target]) {
+                    (None, Infinite) => {}
+                    (Some((path, cost)), Number(d)) => {
+                        assert_eq!(cost, d as i64);
+                        assert_eq!((path[0], *path.last().unwrap()), (0, target));
+                        let weight: i64 = path
+                            .windows(2)
+                            .map(|p| *g.edge_weights_between(p[0], p[1]).iter().min().unwrap())
+                            .sum();
+                        assert_eq!(weight, cost);
+                    }
+                    (path, d) => panic!("{:?} vs {:?}", path, d),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_path_stops_once_target_is_settled() {
+        // O peso negativo só seria visto se a busca continuasse depois de fixar 2
+        let g = DiGraph::from_weighted_edges(&[(0, 2, 5), (0, 1, 6), (1, 2, -10)]);
+        assert_eq!(g.dijkstra_path(0, 2), Some((vec![0, 2], 5)));
+        assert_eq!(g.dijkstra_path(0, 0), Some((vec![0], 0)));
+        assert_eq!(g.dijkstra_path(2, 0), None);
+        assert_eq!(g.dijkstra_path(0, 9), None);
+    }
+
+    #[test]
+    fn shortest_path_dag_keeps_both_equal_cost_paths() {
+        // 0 -> 1 -> 3 e 0 -> 2 -> 3 custam 3; o atalho 0 -> 3 custa 5
+        let g = DiGraph::from_weighted_edges(&[
+            (0, 1, 2),
+            (0, 2, 1),
+            (1, 3, 1),
+            (2, 3, 2),
+            (0, 3, 5),
+            (3, 4, 1),
+            (9, 0, 1),
+        ]);
+        let dag = g.shortest_path_dag(0);
+        let edges: Vec<(i32, i32)> = dag.edges_sorted().iter().map(|e| e.v_w()).collect();
+        assert_eq!(edges, vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+        assert_eq!(dag.vertices_sorted(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(g.shortest_path_dag(42).vertices_length(), 0);
+    }
+
+    #[test]
+    fn shortest_path_dag_edges_are_tight() {
+        let mut rng = StdRng::seed_from_u64(414);
+        for _ in 0..200 {
+            let mut g = DiGraph::new();
+            for _ in 0..16 {
+                let (v, w) = (rng.gen_range(0..8), rng.gen_range(0..8));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(1..6)));
+            }
+            if !g.vertice_exists(0) {
+                continue;
+            }
+            let dist = Dijkstra::shortest_path(&g, 0).dist().clone();
+            let dag = g.shortest_path_dag(0);
+            assert!(dag.is_dag());
+            let kept: HashSet<usize> = dag.all_edges().iter().map(|e| e.id()).collect();
+            for e in g.all_edges() {
+                let tight = match (dist[&e.origin_key()], dist[&e.destiny_key()]) {
+                    (Number(du), Number(dv)) => du + e.weight() == dv,
+                    _ => false,
+                };
+                assert_eq!(kept.contains(&e.id()), tight);
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_f64_matches_integer_dijkstra() {
+        let mut rng = StdRng::seed_from_u64(420);
+        for _ in 0..200 {
+            let g = random_graph(&mut rng, 8, 16);
+            let integer = Dijkstra::shortest_path(&g, 0);
+            let float = g.dijkstra_f64(0, |e| e.weight() as f64 / 4.0);
+            assert_eq!(float.len(), g.vertices_length());
+            for (v, d) in integer.dist() {
+                match d {
+                    Number(d) => assert_eq!(float[v], InfinityF64::new(*d as f64 / 4.0)),
+                    Infinite => assert!(float[v].is_infinite()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_f64_ignores_nan_weights() {
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 0)]);
+        // A aresta 0 -> 2 tem peso NaN e é ignorada
+        let dist = g.dijkstra_f64(0, |e| if e.weight() == 0 { f64::NAN } else { 0.5 });
+        assert_eq!(dist[&2], InfinityF64::new(1.0));
+        assert!(g.dijkstra_f64(9, |_| 1.0).is_empty());
+    }
+
+    #[test]
+    fn both_corridors_are_on_shortest_paths() {
+        // 0 -> 1 -> 3 e 0 -> 2 -> 3 custam 4; por 4 custa 6
+        let g = DiGraph::from_weighted_edges(&[
+            (0, 1, 1),
+            (1, 3, 3),
+            (0, 2, 2),
+            (2, 3, 2),
+            (0, 4, 3),
+            (4, 3, 3),
+            (3, 5, 1),
+        ]);
+        assert_eq!(g.vertices_on_shortest_paths(0, 3), vec![0, 1, 2, 3]);
+        assert_eq!(g.vertices_on_shortest_paths(0, 5), vec![0, 1, 2, 3, 5]);
+        assert_eq!(g.vertices_on_shortest_paths(4, 4), vec![4]);
+        assert!(g.vertices_on_shortest_paths(5, 0).is_empty());
+        assert!(g.vertices_on_shortest_paths(0, 9).is_empty());
+    }
+
+    #[test]
+    fn vertices_on_shortest_paths_match_pairwise_distances() {
+        let mut rng = StdRng::seed_from_u64(428);
+        for _ in 0..100 {
+            let g = random_graph(&mut rng, 7, 14);
+            let dist: HashMap<i32, Dijkstra> = (0..7)
+                .map(|v| (v, Dijkstra::shortest_path(&g, v)))
+                .collect();
+            let (from, to) = (rng.gen_range(0..7), rng.gen_range(0..7));
+            let expected: Vec<i32> = match dist[&from].dist()[&to] {
+                Infinite => vec![],
+                total => (0..7)
+                    .filter(|v| dist[&from].dist()[v] + dist[v].dist()[&to] == total)
+                    .collect(),
+            };
+            assert_eq!(g.vertices_on_shortest_paths(from, to), expected);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use super::dijkstra::Dijkstra;
+use crate::{
+    graph_lib::edge::Edge,
+    tools::{heap::HeapMin, inifinity::Infinity},
+    DiGraph,
+};
+
+/// Caminhos mínimos a partir de uma origem mantidos enquanto arestas são inseridas.
+///
+/// Guarda o grafo e os mesmos mapas de `Bellman` (`pot` e `pred`). Cada inserção só
+/// relaxa a região cuja distância diminuiu, em vez de recalcular tudo.
+///
+/// Suposições:
+/// - pesos não negativos (o grafo inicial e as arestas inseridas)
+/// - apenas inserções: o grafo não é alterado por outro caminho
+pub struct DynamicSSSP {
+    graph: DiGraph,
+    source: i32,
+    pred: HashMap<i32, i32>,
+    pot: HashMap<i32, Infinity>,
+}
+
+use Infinity::*;
+
+#[allow(unused)]
+impl DynamicSSSP {
+    /// Calcula as distâncias iniciais (Dijkstra) a partir de `source`.
+    ///
+    /// `None` se `source` não existir no grafo.
+    pub fn new(graph: DiGraph, source: i32) -> Option<DynamicSSSP> {
+        if !graph.vertice_exists(source) {
+            return None;
+        }
+        let data = Dijkstra::shortest_path(&graph, source);
+        Some(DynamicSSSP {
+            pred: data.pred().clone(),
+            pot: data.dist().clone(),
+            graph,
+            source,
+        })
+    }
+
+    pub fn graph(&self) -> &DiGraph {
+        &self.graph
+    }
+    pub fn source(&self) -> i32 {
+        self.source
+    }
+    pub fn pred(&self) -> &HashMap<i32, i32> {
+        &self.pred
+    }
+    pub fn pot(&self) -> &HashMap<i32, Infinity> {
+        &self.pot
+    }
+
+    /// Devolve o grafo com as arestas inseridas.
+    pub fn into_graph(self) -> DiGraph {
+        self.graph
+    }
+
+    /// Insere a aresta `origin -> destiny` e atualiza as distâncias.
+    ///
+    /// Se a aresta encurta o caminho até `destiny`, propaga a melhora (Dijkstra a partir de
+    /// `destiny`, só pelos vértices cuja distância diminui). Vértices novos entram no grafo
+    /// como inalcançáveis antes da atualização.
+    ///
+    /// Retorna `true` se alguma distância mudou. O peso deve ser não negativo.
+    pub fn add_edge_and_update(&mut self, origin: i32, destiny: i32, weight: i32) -> bool {
+        debug_assert!(weight >= 0, "DynamicSSSP exige pesos não negativos");
+        self.graph.add_edge(Edge::new_weighted(origin, destiny, weight));
+        for key in [origin, destiny] {
+            self.pot.entry(key).or_insert(Infinite);
+            self.pred.entry(key).or_insert(-1);
+        }
+
+        let candidate = self.pot[&origin] + Number(weight);
+        if candidate >= self.pot[&destiny] {
+            return false;
+        }
+        self.pot.insert(destiny, candidate);
+        self.pred.insert(destiny, origin);
+
+        let mut queue = HeapMin::new(|a: &(Infinity, i32), b: &(Infinity, i32)| a.0.cmp(&b.0));
+        queue.insert((candidate, destiny));
+        while let Some((d, v)) = queue.pop() {
+            if d > self.pot[&v] {
+                continue;
+            }
+            for e in self.graph.get_vertice_arc(v).unwrap().edges_vec_ref() {
+                let w = e.destiny_key();
+                let candidate = d + Number(e.weight());
+                if candidate < self.pot[&w] {
+                    self.pot.insert(w, candidate);
+                    self.pred.insert(w, v);
+                    queue.insert((candidate, w));
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn incremental_distances_match_a_full_recomputation() {
+        let mut rng = StdRng::seed_from_u64(426);
+        for _ in 0..100 {
+            let mut g = DiGraph::new();
+            g.add_vertice(0);
+            for _ in 0..8 {
+                let (v, w) = (rng.gen_range(0..8), rng.gen_range(0..8));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(0..10)));
+            }
+            let mut dynamic = DynamicSSSP::new(g, 0).unwrap();
+
+            // Inclui vértices que ainda não existem (8 e 9)
+            for _ in 0..10 {
+                let (v, w) = (rng.gen_range(0..10), rng.gen_range(0..10));
+                let before = dynamic.pot().clone();
+                let changed = dynamic.add_edge_and_update(v, w, rng.gen_range(0..10));
+
+                let fresh = Dijkstra::shortest_path(dynamic.graph(), 0);
+                assert_eq!(dynamic.pot(), fresh.dist());
+                // Vértices novos contam como inalcançáveis antes da inserção
+                let same = dynamic
+                    .pot()
+                    .iter()
+                    .all(|(k, d)| before.get(k).copied().unwrap_or(Infinite) == *d);
+                assert_eq!(changed, !same);
+
+                // Toda distância finita é explicada pela aresta do predecessor
+                for (&k, &d) in dynamic.pot() {
+                    if k == 0 || d.is_infinite() {
+                        continue;
+                    }
+                    let p = dynamic.pred()[&k];
+                    let weights = dynamic.graph().edge_weights_between(p, k);
+                    assert!(weights
+                        .iter()
+                        .any(|&wt| dynamic.pot()[&p] + Number(wt as i32) == d));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn new_requires_an_existing_source() {
+        assert!(DynamicSSSP::new(DiGraph::new(), 0).is_none());
+
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 5)]);
+        let mut dynamic = DynamicSSSP::new(g, 0).unwrap();
+        assert!(!dynamic.add_edge_and_update(1, 0, 3));
+        assert!(dynamic.add_edge_and_update(0, 2, 1));
+        assert!(dynamic.add_edge_and_update(2, 1, 1));
+        assert_eq!(dynamic.pot()[&1], Number(2));
+        assert_eq!(dynamic.pred()[&1], 2);
+        assert_eq!(dynamic.into_graph().edges_length(), 4);
+    }
+}
@@ -1,3 +1,7 @@
 pub mod bellman;
 pub mod dijkstra;
-pub mod floyd_warshall;
\ No newline at end of file
+pub mod floyd_warshall;
+pub mod widest_path;
+pub mod dynamic;
+pub mod turn_costs;
+pub mod suurballe;
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{graph_lib::edge::Edge, tools::heap::HeapMin, DiGraph};
+
+/// Arcos de saída por vértice: `(destino, custo, id da aresta original)`
+type Arcs = HashMap<i32, Vec<(i32, i64, usize)>>;
+
+/// Dijkstra sobre `arcs`: distância de cada vértice alcançado e o arco usado para chegar
+/// nele (`(anterior, id)`).
+fn arc_dijkstra(arcs: &Arcs, source: i32) -> (HashMap<i32, i64>, HashMap<i32, (i32, usize)>) {
+    let mut dist: HashMap<i32, i64> = HashMap::from([(source, 0)]);
+    let mut pred: HashMap<i32, (i32, usize)> = HashMap::new();
+    let mut queue = HeapMin::new(|a: &(i64, i32), b: &(i64, i32)| a.cmp(b));
+    queue.insert((0, source));
+
+    while let Some((d, v)) = queue.pop() {
+        if d > dist[&v] {
+            continue;
+        }
+        for &(w, cost, id) in arcs.get(&v).into_iter().flatten() {
+            let candidate = d + cost;
+            if dist.get(&w).is_none_or(|&current| candidate < current) {
+                dist.insert(w, candidate);
+                pred.insert(w, (v, id));
+                queue.insert((candidate, w));
+            }
+        }
+    }
+    (dist, pred)
+}
+
+/// Ids das arestas do caminho até `target` seguindo `pred`
+fn path_ids(pred: &HashMap<i32, (i32, usize)>, source: i32, target: i32) -> Vec<usize> {
+    let mut ids = Vec::new();
+    let mut current = target;
+    while current != source {
+        let (previous, id) = pred[&current];
+        ids.push(id);
+        current = previous;
+    }
+    ids
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Dois caminhos de `source` até `target` sem arestas em comum e de custo total mínimo
+    /// (algoritmo de Suurballe).
+    ///
+    /// 1. Dijkstra encontra o caminho mínimo `P1` e as distâncias `d`
+    /// 2. Os pesos viram `w + d(u) - d(v)` (não negativos) e as arestas de `P1` são invertidas
+    /// 3. Dijkstra nesse grafo residual encontra `P2`
+    /// 4. As arestas de `P1` que `P2` percorreu ao contrário se cancelam, e o que sobra forma
+    ///    os dois caminhos
+    ///
+    /// Arestas paralelas contam como arestas distintas; os caminhos podem repetir vértices.
+    /// O de menor custo vem primeiro. Supõe pesos não negativos. `None` se não existirem dois
+    /// caminhos disjuntos, se `source == target` ou se algum dos vértices não existir.
+    pub fn two_disjoint_shortest_paths(
+        &self,
+        source: i32,
+        target: i32,
+    ) -> Option<(Vec<i32>, Vec<i32>)> {
+        if source == target || !self.vertice_exists(source) || !self.vertice_exists(target) {
+            return None;
+        }
+        let edges: HashMap<usize, Edge> =
+            self.all_edges().into_iter().map(|e| (e.id(), e)).collect();
+
+        let mut arcs: Arcs = HashMap::new();
+        for e in self.edges_sorted() {
+            let (v, w) = e.v_w();
+            arcs.entry(v)
+                .or_default()
+                .push((w, e.weight() as i64, e.id()));
+        }
+        let (dist, pred) = arc_dijkstra(&arcs, source);
+        dist.get(&target)?;
+        let first: HashSet<usize> = path_ids(&pred, source, target).into_iter().collect();
+
+        // Grafo residual com custos reduzidos, só entre vértices alcançáveis
+        let mut residual: Arcs = HashMap::new();
+        for e in self.edges_sorted() {
+            let (v, w) = e.v_w();
+            let (Some(&d_v), Some(&d_w)) = (dist.get(&v), dist.get(&w)) else {
+                continue;
+            };
+            if first.contains(&e.id()) {
+                residual.entry(w).or_default().push((v, 0, e.id()));
+            } else {
+                let reduced = e.weight() as i64 + d_v - d_w;
+                residual.entry(v).or_default().push((w, reduced, e.id()));
+            }
+        }
+        let (_, residual_pred) = arc_dijkstra(&residual, source);
+        if !residual_pred.contains_key(&target) {
+            return None;
+        }
+        let second: HashSet<usize> = path_ids(&residual_pred, source, target)
+            .into_iter()
+            .collect();
+
+        // Arestas que sobram depois de cancelar os trechos percorridos nos dois sentidos
+        let mut remaining: HashMap<i32, Vec<&Edge>> = HashMap::new();
+        for id in first.symmetric_difference(&second) {
+            let e = &edges[id];
+            remaining.entry(e.origin_key()).or_default().push(e);
+        }
+        for list in remaining.values_mut() {
+            list.sort_by_key(|e| std::cmp::Reverse(e.id()));
+        }
+
+        let mut walk = || {
+            let mut path = vec![source];
+            let mut cost = 0;
+            let mut current = source;
+            while current != target {
+                let e = remaining.get_mut(&current).unwrap().pop().unwrap();
+                cost += e.weight() as i64;
+                current = e.destiny_key();
+                path.push(current);
+            }
+            (cost, path)
+        };
+        let (a, b) = (walk(), walk());
+        let (first_path, second_path) = if a <= b { (a, b) } else { (b, a) };
+        Some((first_path.1, second_path.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Todos os caminhos simples de `v` até `target`, como listas de arestas `(v, w)`
+    fn simple_paths(
+        g: &DiGraph,
+        v: i32,
+        target: i32,
+        on_path: &mut Vec<i32>,
+        edges: &mut Vec<(i32, i32)>,
+        paths: &mut Vec<Vec<(i32, i32)>>,
+    ) {
+        if v == target {
+            paths.push(edges.clone());
+            return;
+        }
+        on_path.push(v);
+        for w in g.get_sucessor(v).unwrap() {
+            if !on_path.contains(&w) {
+                edges.push((v, w));
+                simple_paths(g, w, target, on_path, edges, paths);
+                edges.pop();
+            }
+        }
+        on_path.pop();
+    }
+
+    /// Menor custo total de dois caminhos simples sem arestas em comum (grafo sem paralelas)
+    fn brute_force(g: &DiGraph, source: i32, target: i32) -> Option<i64> {
+        let mut paths = Vec::new();
+        simple_paths(
+            g,
+            source,
+            target,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &mut paths,
+        );
+        let cost = |p: &[(i32, i32)]| -> i64 {
+            p.iter()
+                .map(|&(v, w)| g.edge_weights_between(v, w)[0])
+                .sum()
+        };
+        let mut best = None;
+        for (i, a) in paths.iter().enumerate() {
+            for b in paths[i + 1..].iter() {
+                if a.iter().all(|e| !b.contains(e)) {
+                    let total = cost(a) + cost(b);
+                    best = Some(best.map_or(total, |current: i64| current.min(total)));
+                }
+            }
+        }
+        best
+    }
+
+    /// Pares `(v, w)` percorridos por um caminho de vértices
+    fn path_edges(path: &[i32]) -> Vec<(i32, i32)> {
+        path.windows(2).map(|p| (p[0], p[1])).collect()
+    }
+
+    #[test]
+    fn suurballe_avoids_the_greedy_trap() {
+        // O caminho mínimo 0 -> 1 -> 2 -> 3 bloqueia qualquer segundo caminho, mas
+        // 0 -> 1 -> 3 e 0 -> 2 -> 3 são disjuntos e custam 6 no total
+        let g =
+            DiGraph::from_weighted_edges(&[(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 2, 2), (1, 3, 2)]);
+        assert_eq!(
+            g.two_disjoint_shortest_paths(0, 3),
+            Some((vec![0, 1, 3], vec![0, 2, 3]))
+        );
+        assert_eq!(
+            g.two_disjoint_shortest_paths(0, 2),
+            Some((vec![0, 1, 2], vec![0, 2]))
+        );
+
+        // Um único caminho, origem igual ao destino ou vértice inexistente
+        assert_eq!(g.two_disjoint_shortest_paths(2, 3), None);
+        assert_eq!(g.two_disjoint_shortest_paths(0, 0), None);
+        assert_eq!(g.two_disjoint_shortest_paths(0, 9), None);
+
+        // Arestas paralelas são caminhos distintos
+        let parallel = DiGraph::from_weighted_edges(&[(0, 1, 4), (0, 1, 2)]);
+        assert_eq!(
+            parallel.two_disjoint_shortest_paths(0, 1),
+            Some((vec![0, 1], vec![0, 1]))
+        );
+    }
+
+    #[test]
+    fn suurballe_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(448);
+        let mut found = 0;
+        for _ in 0..300 {
+            // Sem arestas paralelas: cada par de vértices tem no máximo uma aresta
+            let mut g = DiGraph::new();
+            for v in 0..6 {
+                g.add_vertice(v);
+            }
+            for _ in 0..14 {
+                let (v, w) = (rng.gen_range(0..6), rng.gen_range(0..6));
+                if v != w && g.edge_weights_between(v, w).is_empty() {
+                    g.add_edge(Edge::new_weighted(v, w, rng.gen_range(0..10)));
+                }
+            }
+
+            let result = g.two_disjoint_shortest_paths(0, 5);
+            let expected = brute_force(&g, 0, 5);
+            assert_eq!(result.is_some(), expected.is_some());
+            let Some((first, second)) = result else {
+                continue;
+            };
+            found += 1;
+
+            let (a, b) = (path_edges(&first), path_edges(&second));
+            assert!(a.iter().all(|e| !b.contains(e)));
+            let mut all = a.clone();
+            all.extend(b.iter());
+            let cost = |p: &[(i32, i32)]| -> i64 {
+                p.iter()
+                    .map(|&(v, w)| g.edge_weights_between(v, w)[0])
+                    .sum()
+            };
+            assert_eq!(cost(&all), expected.unwrap());
+            assert!(cost(&a) <= cost(&b));
+            assert_eq!((first[0], *first.last().unwrap()), (0, 5));
+            assert_eq!((second[0], *second.last().unwrap()), (0, 5));
+        }
+        assert!(found > 0);
+    }
+}
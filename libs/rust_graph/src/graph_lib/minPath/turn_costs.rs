@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use crate::{graph_lib::edge::Edge, tools::heap::HeapMin, DiGraph};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Caminho mínimo de `source` até `target` com custo de conversão entre arestas.
+    ///
+    /// Dijkstra no grafo expandido por arestas: cada estado é a última aresta percorrida, e
+    /// seguir de `e` para `f` custa `f.weight() + penalty(e, f)`. Assim o custo de uma
+    /// conversão pode depender das duas arestas (ex: proibir ou encarecer retornos).
+    ///
+    /// Retorna os vértices do caminho e o custo total (pesos mais penalidades). Supõe pesos e
+    /// penalidades não negativos. `Some((vec![source], 0))` se `source == target`; `None` se
+    /// `target` for inalcançável ou se algum dos vértices não existir.
+    pub fn shortest_path_with_turn_costs(
+        &self,
+        source: i32,
+        target: i32,
+        penalty: impl Fn(&Edge, &Edge) -> i64,
+    ) -> Option<(Vec<i32>, i64)> {
+        if !self.vertice_exists(source) || !self.vertice_exists(target) {
+            return None;
+        }
+        if source == target {
+            return Some((vec![source], 0));
+        }
+
+        // Custo até terminar em cada aresta (por id) e a aresta anterior no caminho
+        let mut dist: HashMap<usize, i64> = HashMap::new();
+        let mut pred: HashMap<usize, Edge> = HashMap::new();
+        let mut queue = HeapMin::new(|a: &(i64, Edge), b: &(i64, Edge)| a.0.cmp(&b.0));
+        for e in self.get_vertice_arc(source).unwrap().edges_sorted() {
+            let cost = e.weight() as i64;
+            if dist.get(&e.id()).is_none_or(|&d| cost < d) {
+                dist.insert(e.id(), cost);
+                queue.insert((cost, e));
+            }
+        }
+
+        while let Some((cost, e)) = queue.pop() {
+            if cost > dist[&e.id()] {
+                continue;
+            }
+            if e.destiny_key() == target {
+                let mut path = vec![target];
+                let mut current = e;
+                while let Some(previous) = pred.get(&current.id()) {
+                    path.push(previous.destiny_key());
+                    current = previous.clone();
+                }
+                path.push(source);
+                path.reverse();
+                return Some((path, cost));
+            }
+            let vertice = self.get_vertice_arc(e.destiny_key()).unwrap();
+            for f in vertice.edges_sorted() {
+                let candidate = cost + f.weight() as i64 + penalty(&e, &f);
+                if dist.get(&f.id()).is_none_or(|&d| candidate < d) {
+                    dist.insert(f.id(), candidate);
+                    pred.insert(f.id(), e.clone());
+                    queue.insert((candidate, f));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::minPath::dijkstra::Dijkstra;
+    use crate::tools::inifinity::Infinity;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Custo mínimo por relaxamento repetido sobre os estados (última aresta) até estabilizar
+    fn relaxation_cost(
+        g: &DiGraph,
+        source: i32,
+        target: i32,
+        penalty: &impl Fn(&Edge, &Edge) -> i64,
+    ) -> Option<i64> {
+        let edges = g.all_edges();
+        let mut dist: HashMap<usize, i64> = HashMap::new();
+        for e in edges.iter().filter(|e| e.origin_key() == source) {
+            dist.insert(e.id(), e.weight() as i64);
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for e in edges.iter() {
+                let Some(&d) = dist.get(&e.id()) else {
+                    continue;
+                };
+                for f in edges.iter().filter(|f| f.origin_key() == e.destiny_key()) {
+                    let candidate = d + f.weight() as i64 + penalty(e, f);
+                    if dist.get(&f.id()).is_none_or(|&current| candidate < current) {
+                        dist.insert(f.id(), candidate);
+                        changed = true;
+                    }
+                }
+            }
+        }
+        edges
+            .iter()
+            .filter(|e| e.destiny_key() == target)
+            .filter_map(|e| dist.get(&e.id()).copied())
+            .min()
+    }
+
+    #[test]
+    fn turn_penalty_makes_the_longer_route_cheaper() {
+        // 0 -> 1 -> 3 é mais curto, mas virar de (0, 1) para (1, 3) custa 10
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 1), (1, 3, 1), (1, 2, 1), (2, 3, 3)]);
+        let left_turn = |e: &Edge, f: &Edge| {
+            if e.v_w() == (0, 1) && f.v_w() == (1, 3) {
+                10
+            } else {
+                0
+            }
+        };
+        assert_eq!(
+            g.shortest_path_with_turn_costs(0, 3, left_turn),
+            Some((vec![0, 1, 2, 3], 5))
+        );
+        assert_eq!(
+            g.shortest_path_with_turn_costs(0, 3, |_, _| 0),
+            Some((vec![0, 1, 3], 2))
+        );
+        assert_eq!(
+            g.shortest_path_with_turn_costs(2, 2, left_turn),
+            Some((vec![2], 0))
+        );
+        assert_eq!(g.shortest_path_with_turn_costs(3, 0, left_turn), None);
+        assert_eq!(g.shortest_path_with_turn_costs(0, 9, left_turn), None);
+    }
+
+    #[test]
+    fn turn_costs_match_state_relaxation() {
+        let mut rng = StdRng::seed_from_u64(446);
+        for _ in 0..100 {
+            let edges: Vec<(i32, i32, i32)> = (0..14)
+                .map(|_| {
+                    (
+                        rng.gen_range(0..6),
+                        rng.gen_range(0..6),
+                        rng.gen_range(0..8),
+                    )
+                })
+                .collect();
+            let mut g = DiGraph::from_weighted_edges(&edges);
+            g.add_vertice(0);
+            let target = rng.gen_range(1..6);
+            if !g.vertice_exists(target) {
+                continue;
+            }
+            // Retornos (voltar ao vértice de onde se veio) custam caro
+            let u_turn = |e: &Edge, f: &Edge| {
+                if f.destiny_key() == e.origin_key() {
+                    7
+                } else {
+                    0
+                }
+            };
+            let result = g.shortest_path_with_turn_costs(0, target, u_turn);
+            assert_eq!(
+                result.as_ref().map(|r| r.1),
+                relaxation_cost(&g, 0, target, &u_turn)
+            );
+
+            // O custo devolvido é o de algum conjunto de arestas ao longo do caminho
+            if let Some((path, cost)) = result {
+                assert_eq!((path[0], *path.last().unwrap()), (0, target));
+                assert!(path
+                    .windows(2)
+                    .all(|p| !g.edge_weights_between(p[0], p[1]).is_empty()));
+                let plain: i64 = path
+                    .windows(2)
+                    .map(|p| *g.edge_weights_between(p[0], p[1]).iter().min().unwrap())
+                    .sum();
+                assert!(cost >= plain);
+            }
+
+            // Sem penalidades, o mesmo custo que Dijkstra
+            let zero = g.shortest_path_with_turn_costs(0, target, |_, _| 0);
+            match Dijkstra::shortest_path(&g, 0).dist()[&target] {
+                Infinity::Number(d) => assert_eq!(zero.unwrap().1, d as i64),
+                _ => assert!(zero.is_none()),
+            }
+        }
+    }
+}
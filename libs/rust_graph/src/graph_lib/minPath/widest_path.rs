@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use crate::{tools::heap::HeapMin, tools::inifinity::Infinity, DiGraph};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Caminho mais largo (gargalo máximo) de `source` até cada vértice.
+    ///
+    /// O valor de um vértice é o maior gargalo possível, isto é, o maior valor de
+    /// "menor peso de aresta no caminho" entre todos os caminhos de `source` até ele.
+    /// Dijkstra modificado: a chave é o gargalo, maximizado em vez de minimizado.
+    ///
+    /// `source` vale `Infinite` (caminho vazio, sem gargalo). Vértices inalcançáveis não
+    /// aparecem no mapa; vazio se `source` não existir.
+    pub fn widest_path(&self, source: i32) -> HashMap<i32, Infinity> {
+        self.widest_path_search(source, None).0
+    }
+
+    /// Caminho mais largo de `source` até `target` e o seu gargalo (ver `widest_path`).
+    ///
+    /// A busca para assim que `target` é fixado. `None` se `target` for inalcançável
+    /// ou se algum dos vértices não existir.
+    pub fn widest_path_to(&self, source: i32, target: i32) -> Option<(Vec<i32>, Infinity)> {
+        if !self.vertice_exists(target) {
+            return None;
+        }
+        let (width, pred) = self.widest_path_search(source, Some(target));
+        let bottleneck = *width.get(&target)?;
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(&p) = pred.get(&current) {
+            path.push(p);
+            current = p;
+        }
+        path.reverse();
+        Some((path, bottleneck))
+    }
+
+    /// Retorna o gargalo de cada vértice alcançado e o predecessor no caminho mais largo.
+    fn widest_path_search(
+        &self,
+        source: i32,
+        target: Option<i32>,
+    ) -> (HashMap<i32, Infinity>, HashMap<i32, i32>) {
+        let mut width: HashMap<i32, Infinity> = HashMap::new();
+        let mut pred: HashMap<i32, i32> = HashMap::new();
+        if !self.vertice_exists(source) {
+            return (width, pred);
+        }
+        width.insert(source, Infinity::Infinite);
+        // Heap de mínimo com a comparação invertida: sai primeiro o maior gargalo
+        let mut queue = HeapMin::new(|a: &(Infinity, i32), b: &(Infinity, i32)| b.0.cmp(&a.0));
+        queue.insert((Infinity::Infinite, source));
+
+        while let Some((w_v, v)) = queue.pop() {
+            if w_v < width[&v] {
+                continue;
+            }
+            if Some(v) == target {
+                break;
+            }
+            for e in self.get_vertice_arc(v).unwrap().edges_vec_ref() {
+                let w = e.destiny_key();
+                let candidate = w_v.min(Infinity::new(e.weight()));
+                if width.get(&w).is_none_or(|&current| candidate > current) {
+                    width.insert(w, candidate);
+                    pred.insert(w, v);
+                    queue.insert((candidate, w));
+                }
+            }
+        }
+        (width, pred)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Maior gargalo de `v` até cada vértice, enumerando os caminhos simples
+    fn brute_force(
+        g: &DiGraph,
+        v: i32,
+        bottleneck: Infinity,
+        on_path: &mut Vec<i32>,
+        best: &mut HashMap<i32, Infinity>,
+    ) {
+        if best.get(&v).is_none_or(|&b| bottleneck > b) {
+            best.insert(v, bottleneck);
+        }
+        on_path.push(v);
+        for e in g.get_vertice_arc(v).unwrap().edges_sorted() {
+            let w = e.destiny_key();
+            if !on_path.contains(&w) {
+                brute_force(
+                    g,
+                    w,
+                    bottleneck.min(Infinity::new(e.weight())),
+                    on_path,
+                    best,
+                );
+            }
+        }
+        on_path.pop();
+    }
+
+    #[test]
+    fn bottlenecks_of_a_weighted_graph() {
+        // Até 3: 0 -> 1 -> 3 tem gargalo 4, 0 -> 2 -> 3 só 2
+        let g = DiGraph::from_weighted_edges(&[
+            (0, 1, 5),
+            (1, 3, 4),
+            (0, 2, 10),
+            (2, 3, 2),
+            (3, 4, 7),
+            (5, 0, 1),
+        ]);
+        let width = g.widest_path(0);
+        assert_eq!(
+            width,
+            HashMap::from([
+                (0, Infinity::Infinite),
+                (1, Infinity::new(5)),
+                (2, Infinity::new(10)),
+                (3, Infinity::new(4)),
+                (4, Infinity::new(4)),
+            ])
+        );
+        assert_eq!(
+            g.widest_path_to(0, 4),
+            Some((vec![0, 1, 3, 4], Infinity::new(4)))
+        );
+        assert_eq!(g.widest_path_to(0, 0), Some((vec![0], Infinity::Infinite)));
+        assert_eq!(g.widest_path_to(0, 5), None);
+        assert!(g.widest_path(9).is_empty());
+    }
+
+    #[test]
+    fn widest_path_matches_path_enumeration() {
+        let mut rng = StdRng::seed_from_u64(422);
+        for _ in 0..200 {
+            let mut g = DiGraph::new();
+            g.add_vertice(0);
+            for _ in 0..12 {
+                let (v, w) = (rng.gen_range(0..6), rng.gen_range(0..6));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(-3..10)));
+            }
+            let mut expected = HashMap::new();
+            brute_force(&g, 0, Infinity::Infinite, &mut Vec::new(), &mut expected);
+            assert_eq!(g.widest_path(0), expected);
+
+            for (&target, &bottleneck) in expected.iter() {
+                let (path, width) = g.widest_path_to(0, target).unwrap();
+                assert_eq!(width, bottleneck);
+                // O caminho devolvido realmente tem esse gargalo
+                let along = path
+                    .windows(2)
+                    .map(|p| {
+                        Infinity::new(
+                            *g.edge_weights_between(p[0], p[1]).iter().max().unwrap() as i32
+                        )
+                    })
+                    .min()
+                    .unwrap_or(Infinity::Infinite);
+                assert_eq!(along, bottleneck);
+            }
+        }
+    }
+}
@@ -1,8 +1,13 @@
 pub mod edge;
 pub mod flux;
 pub mod graph;
+pub mod graph_view;
+pub mod matching;
+pub mod metrics;
 pub mod minPath;
+pub mod sampling;
 pub mod search;
+pub mod transform;
 pub mod vertice;
 pub mod view;
 pub mod mst;
@@ -0,0 +1,293 @@
+// Chu-Liu/Edmonds algorithm for finding the minimum spanning arborescence of a digraph
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+/// Aresta usada durante as contrações.
+///
+/// `origin` é o índice da aresta correspondente no nível anterior da recursão
+struct ArcEdge {
+    from: i32,
+    to: i32,
+    weight: i64,
+    origin: usize,
+}
+
+/// Encontra um ciclo no grafo formado pelas arestas de entrada mínimas, se existir.
+fn find_cycle(
+    vertices: &[i32],
+    edges: &[ArcEdge],
+    min_in: &HashMap<i32, usize>,
+    root: i32,
+) -> Option<Vec<i32>> {
+    let mut walk_of: HashMap<i32, usize> = HashMap::with_capacity(vertices.len());
+    for (walk, &start) in vertices.iter().enumerate() {
+        let mut v = start;
+        while v != root && !walk_of.contains_key(&v) {
+            walk_of.insert(v, walk);
+            v = edges[min_in[&v]].from;
+        }
+        // voltou a um vértice visitado nesta mesma caminhada: ciclo
+        if v != root && walk_of[&v] == walk {
+            let mut cycle = vec![v];
+            let mut u = edges[min_in[&v]].from;
+            while u != v {
+                cycle.push(u);
+                u = edges[min_in[&u]].from;
+            }
+            return Some(cycle);
+        }
+    }
+    None
+}
+
+/// Chave livre para o vértice contraído: a maior chave mais um ou, se ela for `i32::MAX`,
+/// a menor chave sem uso.
+fn fresh_key(vertices: &[i32]) -> i32 {
+    let max = *vertices.iter().max().unwrap();
+    if let Some(key) = max.checked_add(1) {
+        return key;
+    }
+    let used: HashSet<i32> = vertices.iter().copied().collect();
+    (i32::MIN..).find(|k| !used.contains(k)).unwrap()
+}
+
+/// Retorna os índices (em `edges`) das arestas da arborescência mínima.
+fn edmonds(vertices: &[i32], edges: &[ArcEdge], root: i32) -> Option<Vec<usize>> {
+    // 1. Escolhe a aresta de entrada mais barata de cada vértice
+    let mut min_in: HashMap<i32, usize> = HashMap::with_capacity(vertices.len());
+    for (i, e) in edges.iter().enumerate() {
+        if e.from == e.to || e.to == root {
+            continue;
+        }
+        match min_in.get(&e.to) {
+            Some(&j) if edges[j].weight <= e.weight => {}
+            _ => {
+                min_in.insert(e.to, i);
+            }
+        }
+    }
+    if vertices.iter().any(|v| *v != root && !min_in.contains_key(v)) {
+        return None;
+    }
+
+    // 2. Sem ciclos, as arestas escolhidas já formam a arborescência
+    let Some(cycle) = find_cycle(vertices, edges, &min_in, root) else {
+        return Some(min_in.into_values().collect());
+    };
+
+    // 3. Contrai o ciclo em um novo vértice `c`, ajustando os pesos das arestas que entram nele
+    let in_cycle: HashSet<i32> = cycle.iter().copied().collect();
+    let c = fresh_key(vertices);
+    let mut contracted_vertices: Vec<i32> = vertices
+        .iter()
+        .copied()
+        .filter(|v| !in_cycle.contains(v))
+        .collect();
+    contracted_vertices.push(c);
+
+    let mut contracted_edges = Vec::with_capacity(edges.len());
+    for (i, e) in edges.iter().enumerate() {
+        let (from_in, to_in) = (in_cycle.contains(&e.from), in_cycle.contains(&e.to));
+        let (from, to, weight) = match (from_in, to_in) {
+            (true, true) => continue,
+            (false, true) => (e.from, c, e.weight - edges[min_in[&e.to]].weight),
+            (true, false) => (c, e.to, e.weight),
+            (false, false) => (e.from, e.to, e.weight),
+        };
+        contracted_edges.push(ArcEdge {
+            from,
+            to,
+            weight,
+            origin: i,
+        });
+    }
+
+    // 4. Resolve o grafo contraído e expande o ciclo, removendo a aresta substituída
+    let chosen = edmonds(&contracted_vertices, &contracted_edges, root)?;
+    let entering = chosen
+        .iter()
+        .map(|&j| &contracted_edges[j])
+        .find(|e| e.to == c)?
+        .origin;
+    let broken = edges[entering].to;
+
+    let mut result: Vec<usize> = chosen.iter().map(|&j| contracted_edges[j].origin).collect();
+    for v in cycle {
+        if v != broken {
+            result.push(min_in[&v]);
+        }
+    }
+    Some(result)
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// # Algoritmo de Chu-Liu/Edmonds
+    ///
+    /// Encontra a arborescência geradora de custo mínimo enraizada em `root`:
+    /// todo vértice diferente da raiz recebe exatamente uma aresta e é alcançável a partir dela.
+    ///
+    /// Escolhe a aresta de entrada mais barata de cada vértice; quando essas arestas formam um ciclo,
+    /// o ciclo é contraído em um único vértice e o problema é resolvido recursivamente.
+    ///
+    /// Retorna `None` se `root` não existir ou se algum vértice não for alcançável a partir dela.
+    pub fn min_arborescence(&self, root: i32) -> Option<Vec<Edge>> {
+        if !self.vertice_exists(root) {
+            return None;
+        }
+        let all_edges = self.edges_sorted();
+        let edges: Vec<ArcEdge> = all_edges
+            .iter()
+            .enumerate()
+            .map(|(i, e)| ArcEdge {
+                from: e.origin_key(),
+                to: e.destiny_key(),
+                weight: e.weight() as i64,
+                origin: i,
+            })
+            .collect();
+
+        let chosen = edmonds(&self.vertices_sorted(), &edges, root)?;
+        let mut arborescence: Vec<Edge> = chosen.into_iter().map(|i| all_edges[i].clone()).collect();
+        arborescence.sort_by_key(|e| (e.origin_key(), e.destiny_key(), e.id()));
+        Some(arborescence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn total(edges: &[Edge]) -> i64 {
+        edges.iter().map(|e| e.weight() as i64).sum()
+    }
+
+    /// Escolhe uma aresta de entrada por vértice de todas as formas possíveis
+    fn brute_force(g: &DiGraph, root: i32) -> Option<i64> {
+        let vertices: Vec<i32> = g
+            .vertices_sorted()
+            .into_iter()
+            .filter(|&v| v != root)
+            .collect();
+        let incoming: Vec<Vec<Edge>> = vertices
+            .iter()
+            .map(|&v| {
+                g.all_edges()
+                    .into_iter()
+                    .filter(|e| e.destiny_key() == v && e.origin_key() != v)
+                    .collect()
+            })
+            .collect();
+        let mut best = None;
+        let mut choice = vec![0; vertices.len()];
+        loop {
+            if incoming.iter().all(|list| !list.is_empty()) {
+                let parent: HashMap<i32, i32> = vertices
+                    .iter()
+                    .zip(choice.iter())
+                    .enumerate()
+                    .map(|(i, (&v, &c))| (v, incoming[i][c].origin_key()))
+                    .collect();
+                // Sem ciclos: subindo pelos pais, todo vértice chega na raiz
+                let reaches_root = vertices.iter().all(|&v| {
+                    let mut u = v;
+                    for _ in 0..=vertices.len() {
+                        if u == root {
+                            return true;
+                        }
+                        u = parent[&u];
+                    }
+                    false
+                });
+                if reaches_root {
+                    let cost: i64 = (0..vertices.len())
+                        .map(|i| incoming[i][choice[i]].weight() as i64)
+                        .sum();
+                    best = Some(best.map_or(cost, |b: i64| b.min(cost)));
+                }
+            }
+            // Próxima combinação
+            let mut i = 0;
+            while i < choice.len() && choice[i] + 1 >= incoming[i].len().max(1) {
+                choice[i] = 0;
+                i += 1;
+            }
+            if i == choice.len() {
+                return best;
+            }
+            choice[i] += 1;
+        }
+    }
+
+    #[test]
+    fn min_arborescence_known_answer() {
+        // As entradas mínimas de 1 e 2 formam o ciclo 1 <-> 2, que é contraído
+        let g = DiGraph::from_weighted_edges(&[
+            (0, 1, 10),
+            (0, 2, 12),
+            (1, 2, 1),
+            (2, 1, 1),
+            (1, 3, 2),
+            (2, 3, 4),
+            (3, 2, 8),
+        ]);
+        let tree = g.min_arborescence(0).unwrap();
+        let arcs: Vec<(i32, i32)> = tree.iter().map(|e| e.v_w()).collect();
+        assert_eq!(arcs, vec![(0, 1), (1, 2), (1, 3)]);
+        assert_eq!(total(&tree), 13);
+    }
+
+    #[test]
+    fn min_arborescence_contracts_a_cycle_at_the_key_limits() {
+        // Mesmo grafo de `min_arborescence_known_answer`, com o ciclo em `i32::MAX`
+        let (root, a, b, d) = (i32::MIN, i32::MAX, i32::MAX - 1, i32::MIN + 1);
+        let g = DiGraph::from_weighted_edges(&[
+            (root, a, 10),
+            (root, b, 12),
+            (a, b, 1),
+            (b, a, 1),
+            (a, d, 2),
+            (b, d, 4),
+            (d, b, 8),
+        ]);
+        let tree = g.min_arborescence(root).unwrap();
+        let mut arcs: Vec<(i32, i32)> = tree.iter().map(|e| e.v_w()).collect();
+        arcs.sort();
+        assert_eq!(arcs, vec![(root, a), (a, d), (a, b)]);
+        assert_eq!(total(&tree), 13);
+        assert_eq!(fresh_key(&[i32::MIN, i32::MAX, i32::MIN + 1]), i32::MIN + 2);
+        assert_eq!(fresh_key(&[3, -1]), 4);
+    }
+
+    #[test]
+    fn min_arborescence_none_when_unreachable() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (2, 1)]);
+        assert!(g.min_arborescence(0).is_none());
+        assert!(g.min_arborescence(7).is_none());
+        assert!(g.min_arborescence(2).is_none());
+    }
+
+    #[test]
+    fn min_arborescence_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(357);
+        for _ in 0..300 {
+            let n = rng.gen_range(1..6);
+            let mut g = DiGraph::new();
+            for v in 0..n {
+                g.add_vertice(v);
+            }
+            for _ in 0..rng.gen_range(0..12) {
+                let (v, w) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(0..20)));
+            }
+            let tree = g.min_arborescence(0);
+            assert_eq!(tree.as_deref().map(total), brute_force(&g, 0));
+            if let Some(tree) = tree {
+                assert_eq!(tree.len() as i32, n - 1);
+            }
+        }
+    }
+}
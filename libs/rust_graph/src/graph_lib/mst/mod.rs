@@ -1 +1,3 @@
-pub mod prim;
\ No newline at end of file
+pub mod prim;
+pub mod edmonds;
+pub mod steiner;
@@ -58,41 +58,42 @@ pub fn mst_from_graph(graph: &DiGraph, v: i32) -> DiGraph {
         mst.add_edge(min_edge.clone());
 
         // Adiciona o novo vértice à MST
-        if v_in_mst.contains(&min_edge.origin_key()) {
+        let new_vertice = if v_in_mst.contains(&min_edge.origin_key()) {
             min_edge.destiny_key()
         } else {
             min_edge.origin_key()
         };
+        v_in_mst.insert(new_vertice);
     }
-
-    println!("Construção da MST concluída.");
-    println!("Total de vértices na MST: {}", mst.vertices_length());
-    println!("Total de arestas na MST: {}", mst.edges_length());
     mst
 }
 
+/// Aresta de menor peso; empates ficam com o menor id, para o resultado não depender
+/// da ordem dos candidatos.
 pub fn get_minimum_edge(edges: &Vec<Edge>) -> Edge {
-    println!(
-        "Buscando a aresta com o menor peso dentre {} arestas candidatas.",
-        edges.len()
-    );
-    let mut min = &edges[0];
-    for e in edges.iter().skip(1) {
-        if e.weight() < min.weight() {
-            println!(
-                "Aresta {:?} tem peso menor ({}) do que a aresta atual mínima {:?} (Peso: {}).",
-                e,
-                e.weight(),
-                min,
-                min.weight()
-            );
-            min = e;
-        }
+    edges
+        .iter()
+        .min_by_key(|e| (e.weight(), e.id()))
+        .unwrap()
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mst_ignores_direction_and_picks_light_edges() {
+        let g =
+            DiGraph::from_weighted_edges(&[(1, 2, 4), (3, 1, 1), (2, 3, 2), (3, 4, 7), (4, 2, 3)]);
+        let mst = mst_from_graph(&g, 1);
+        assert_eq!(mst.vertices_length(), 4);
+        let mut arcs: Vec<(i32, i32, i32)> = mst
+            .all_edges()
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+        arcs.sort();
+        assert_eq!(arcs, vec![(2, 3, 2), (3, 1, 1), (4, 2, 3)]);
     }
-    println!(
-        "Aresta mínima encontrada: {:?} (Peso: {})",
-        min,
-        min.weight()
-    );
-    min.clone()
 }
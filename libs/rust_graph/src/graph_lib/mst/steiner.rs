@@ -0,0 +1,119 @@
+// Heurística de 2-aproximação para a árvore de Steiner (fecho métrico + MST)
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    graph_lib::{edge::Edge, minPath::dijkstra::Dijkstra, mst::prim::mst_from_graph},
+    tools::inifinity::Infinity,
+    DiGraph,
+};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Árvore de Steiner aproximada conectando os `terminals`.
+    ///
+    /// Implementa a 2-aproximação clássica:
+    /// 1. monta o fecho métrico entre os terminais (`pairwise_shortest_paths`)
+    /// 2. calcula a MST (Prim) desse fecho
+    /// 3. expande cada aresta da MST no caminho mínimo correspondente do grafo
+    ///
+    /// A direção das arestas é usada apenas para escolher o caminho: a distância entre dois
+    /// terminais é a menor entre os dois sentidos. Arestas compartilhadas por vários caminhos
+    /// aparecem uma única vez. Terminais que não se alcançam em nenhum sentido ficam em
+    /// árvores separadas, e terminais inexistentes são ignorados.
+    ///
+    /// Retorna as arestas escolhidas e a soma dos seus pesos.
+    pub fn steiner_tree_approx(&self, terminals: &[i32]) -> (Vec<Edge>, i64) {
+        let closure = self.pairwise_shortest_paths(terminals);
+        let mut terminals: Vec<i32> = terminals
+            .iter()
+            .copied()
+            .filter(|&t| self.vertice_exists(t))
+            .collect();
+        terminals.sort();
+        terminals.dedup();
+
+        // Distância simétrica no fecho e o sentido em que ela é atingida
+        let closure_edge = |a: i32, b: i32| -> Option<(Infinity, i32, i32)> {
+            let (ab, ba) = (closure[&(a, b)], closure[&(b, a)]);
+            let best = if ab <= ba { (ab, a, b) } else { (ba, b, a) };
+            (!best.0.is_infinite()).then_some(best)
+        };
+
+        // Fecho métrico como grafo: uma aresta por par de terminais, no sentido mais curto
+        let mut closure_graph = DiGraph::new();
+        for (i, &a) in terminals.iter().enumerate() {
+            closure_graph.add_vertice(a);
+            for &b in terminals[i + 1..].iter() {
+                if let Some((Infinity::Number(d), from, to)) = closure_edge(a, b) {
+                    closure_graph.add_edge(Edge::new_weighted(from, to, d));
+                }
+            }
+        }
+
+        // Prim (`mst_from_graph`) sobre o fecho, reiniciando em cada parte desconexa
+        let mut in_tree: HashSet<i32> = HashSet::with_capacity(terminals.len());
+        let mut tree_arcs: Vec<(i32, i32)> = Vec::new();
+        for &root in terminals.iter() {
+            if !in_tree.insert(root) {
+                continue;
+            }
+            for e in mst_from_graph(&closure_graph, root).edges_sorted() {
+                in_tree.insert(e.origin_key());
+                in_tree.insert(e.destiny_key());
+                tree_arcs.push(e.v_w());
+            }
+        }
+
+        // Expande cada aresta do fecho no caminho mínimo original
+        let mut runs: HashMap<i32, Dijkstra> = HashMap::new();
+        let mut used: HashSet<usize> = HashSet::new();
+        let mut edges: Vec<Edge> = Vec::new();
+        for (from, to) in tree_arcs {
+            let data = runs
+                .entry(from)
+                .or_insert_with(|| Dijkstra::shortest_path(self, from));
+            let mut w = to;
+            while w != from {
+                let v = data.pred()[&w];
+                let edge = self
+                    .get_edges(v, w)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .min_by_key(|e| (e.weight(), e.id()))
+                    .unwrap();
+                if used.insert(edge.id()) {
+                    edges.push(edge);
+                }
+                w = v;
+            }
+        }
+        edges.sort_by_key(|e| (e.origin_key(), e.destiny_key(), e.id()));
+        let total = edges.iter().map(|e| e.weight() as i64).sum();
+        (edges, total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DiGraph;
+
+    #[test]
+    fn steiner_tree_shares_the_hub() {
+        // Os terminais 1, 2 e 3 se ligam pelo vértice 0; o atalho 2 -> 3 é caro
+        let g =
+            DiGraph::from_weighted_edges(&[(1, 0, 1), (0, 2, 1), (0, 3, 1), (2, 3, 5), (4, 1, 9)]);
+        let (edges, total) = g.steiner_tree_approx(&[1, 2, 3]);
+        let arcs: Vec<(i32, i32)> = edges.iter().map(|e| e.v_w()).collect();
+        assert_eq!(arcs, vec![(0, 2), (0, 3), (1, 0)]);
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn steiner_tree_keeps_unreachable_terminals_apart() {
+        let g = DiGraph::from_weighted_edges(&[(1, 2, 4), (3, 4, 2)]);
+        let (edges, total) = g.steiner_tree_approx(&[1, 2, 3, 4, 99]);
+        assert_eq!(edges.len(), 2);
+        assert_eq!(total, 6);
+    }
+}
@@ -0,0 +1,111 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng, Rng, SeedableRng};
+
+use crate::DiGraph;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Passeio aleatório uniforme a partir de `start`.
+    ///
+    /// A cada passo escolhe um sucessor (chaves distintas, em ordem crescente) com a mesma
+    /// probabilidade. Para antes de `length` passos se chegar a um vértice sem sucessores.
+    ///
+    /// O resultado contém `start` seguido dos vértices visitados (no máximo `length + 1` chaves)
+    /// e é sempre o mesmo para o mesmo `seed`. Retorna vazio se `start` não existir.
+    pub fn random_walk(&self, start: i32, length: usize, seed: u64) -> Vec<i32> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.walk(start, length, |graph, v| {
+            let mut successors = graph.get_sucessor(v)?;
+            if successors.is_empty() {
+                return None;
+            }
+            successors.sort();
+            Some(successors[rng.gen_range(0..successors.len())])
+        })
+    }
+
+    /// Passeio aleatório ponderado a partir de `start`.
+    ///
+    /// A próxima aresta é escolhida com probabilidade **proporcional ao peso** (`weight`),
+    /// considerando cada aresta paralela separadamente. Arestas com peso ≤ 0 nunca são escolhidas;
+    /// se nenhuma aresta tiver peso positivo o passeio para.
+    ///
+    /// Assim como `random_walk`, é determinístico dado o `seed`.
+    pub fn weighted_random_walk(&self, start: i32, length: usize, seed: u64) -> Vec<i32> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.walk(start, length, |graph, v| {
+            let edges = graph.get_vertice_arc(v)?.edges_sorted();
+            let weights = edges.iter().map(|e| e.weight().max(0));
+            let index = WeightedIndex::new(weights).ok()?;
+            Some(edges[index.sample(&mut rng)].destiny_key())
+        })
+    }
+
+    /// Percorre o grafo usando `next` para escolher o próximo vértice até `length` passos.
+    fn walk<F>(&self, start: i32, length: usize, mut next: F) -> Vec<i32>
+    where
+        F: FnMut(&DiGraph, i32) -> Option<i32>,
+    {
+        if !self.vertice_exists(start) {
+            return vec![];
+        }
+        let mut walk = Vec::with_capacity(length + 1);
+        walk.push(start);
+        let mut current = start;
+        for _ in 0..length {
+            let Some(w) = next(self, current) else {
+                break;
+            };
+            walk.push(w);
+            current = w;
+        }
+        walk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn follows_edges(g: &DiGraph, walk: &[i32]) -> bool {
+        walk.windows(2).all(|pair| g.has_edge(pair[0], pair[1]))
+    }
+
+    #[test]
+    fn random_walk_is_reproducible_for_a_seed() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]);
+        let walk = g.random_walk(0, 50, 365);
+        assert_eq!(walk.len(), 51);
+        assert_eq!(walk[0], 0);
+        assert!(follows_edges(&g, &walk));
+        assert_eq!(g.random_walk(0, 50, 365), walk);
+        assert_ne!(g.random_walk(0, 50, 366), walk);
+
+        let weighted = g.weighted_random_walk(0, 50, 365);
+        assert!(follows_edges(&g, &weighted));
+        assert_eq!(g.weighted_random_walk(0, 50, 365), weighted);
+    }
+
+    #[test]
+    fn random_walk_stops_at_sinks() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2)]);
+        assert_eq!(g.random_walk(0, 10, 1), vec![0, 1, 2]);
+        assert_eq!(g.weighted_random_walk(2, 10, 1), vec![2]);
+        assert!(g.random_walk(9, 10, 1).is_empty());
+    }
+
+    #[test]
+    fn weighted_walk_is_proportional_to_weight() {
+        // A partir de 0: peso 3 para 1, peso 1 para 2 e peso 0 para 3 (nunca escolhida)
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 3), (0, 2, 1), (0, 3, 0)]);
+        let mut counts: HashMap<i32, usize> = HashMap::new();
+        for seed in 0..4000 {
+            *counts
+                .entry(g.weighted_random_walk(0, 1, seed)[1])
+                .or_insert(0) += 1;
+        }
+        assert!(!counts.contains_key(&3));
+        let ratio = counts[&1] as f64 / counts[&2] as f64;
+        assert!((2.5..3.5).contains(&ratio), "razão {}", ratio);
+    }
+}
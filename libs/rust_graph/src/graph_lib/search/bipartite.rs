@@ -0,0 +1,73 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::DiGraph;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Tenta dividir os vértices em dois conjuntos (L, R) tal que toda aresta
+    /// liga um vértice de L a um vértice de R.
+    ///
+    /// A direção das arestas é ignorada. Cada componente é colorido por uma busca em largura
+    /// começando pelo menor vértice ainda não colorido, que vai para L.
+    ///
+    /// Retorna `None` se o grafo não for bipartido (ex: ciclo ímpar ou laço).
+    pub fn bipartition(&self) -> Option<(Vec<i32>, Vec<i32>)> {
+        let mut color: HashMap<i32, bool> = HashMap::with_capacity(self.vertices_length());
+        let mut keys = self.get_vertice_key_array();
+        keys.sort();
+
+        for &root in keys.iter() {
+            if color.contains_key(&root) {
+                continue;
+            }
+            color.insert(root, false);
+            let mut queue = VecDeque::from([root]);
+
+            while let Some(v) = queue.pop_front() {
+                let v_color = color[&v];
+                for w in self.undirected_neighbors(v).unwrap_or_default() {
+                    match color.get(&w) {
+                        Some(&w_color) if w_color == v_color => return None,
+                        Some(_) => {}
+                        None => {
+                            color.insert(w, !v_color);
+                            queue.push_back(w);
+                        }
+                    }
+                }
+            }
+        }
+
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        for key in keys {
+            if color[&key] {
+                right.push(key);
+            } else {
+                left.push(key);
+            }
+        }
+        Some((left, right))
+    }
+
+    /// Verifica se o grafo (sem considerar direção) é bipartido.
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bipartition_of_even_cycle() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 3), (3, 0), (5, 4)]);
+        assert_eq!(g.bipartition(), Some((vec![0, 2, 4], vec![1, 3, 5])));
+    }
+
+    #[test]
+    fn self_loop_is_not_bipartite() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 1)]);
+        assert!(!g.is_bipartite());
+    }
+}
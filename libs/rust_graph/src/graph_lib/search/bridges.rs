@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+/// Lista de adjacência sem direção: `(vizinho, id da aresta)` por vértice, sem laços
+type UndirectedAdjacency = HashMap<i32, Vec<(i32, usize)>>;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Adjacência não direcionada ordenada (laços não afetam pontes nem componentes)
+    fn undirected_adjacency(&self) -> UndirectedAdjacency {
+        let mut adj: UndirectedAdjacency = self
+            .get_vertice_key_array()
+            .into_iter()
+            .map(|v| (v, Vec::new()))
+            .collect();
+        for e in self.edges_sorted() {
+            let (v, w) = e.v_w();
+            if v != w {
+                adj.get_mut(&v).unwrap().push((w, e.id()));
+                adj.get_mut(&w).unwrap().push((v, e.id()));
+            }
+        }
+        for list in adj.values_mut() {
+            list.sort();
+        }
+        adj
+    }
+
+    /// Pontes do grafo, ignorando a direção das arestas (Tarjan, tempos de descoberta e `low`).
+    ///
+    /// Uma ponte é uma aresta cuja remoção desconecta suas extremidades. Arestas paralelas
+    /// nunca são pontes (a volta pela aresta de onde se veio é pulada pelo id, não pelo
+    /// vértice) e laços são ignorados. Ordenadas por origem, destino e id.
+    pub fn bridges(&self) -> Vec<Edge> {
+        let adj = self.undirected_adjacency();
+        let mut disc: HashMap<i32, usize> = HashMap::with_capacity(adj.len());
+        let mut low: HashMap<i32, usize> = HashMap::with_capacity(adj.len());
+        let mut bridge_ids: HashSet<usize> = HashSet::new();
+        let mut time = 0;
+
+        for root in self.vertices_sorted() {
+            if disc.contains_key(&root) {
+                continue;
+            }
+            disc.insert(root, time);
+            low.insert(root, time);
+            time += 1;
+            // (vértice, aresta usada para chegar nele, próximo vizinho a examinar)
+            let mut stack: Vec<(i32, Option<usize>, usize)> = vec![(root, None, 0)];
+
+            while let Some(top) = stack.last_mut() {
+                let (v, parent_edge) = (top.0, top.1);
+                if let Some(&(w, id)) = adj[&v].get(top.2) {
+                    top.2 += 1;
+                    if Some(id) == parent_edge {
+                        continue;
+                    }
+                    if let Some(&d) = disc.get(&w) {
+                        let low_v = low[&v].min(d);
+                        low.insert(v, low_v);
+                    } else {
+                        disc.insert(w, time);
+                        low.insert(w, time);
+                        time += 1;
+                        stack.push((w, Some(id), 0));
+                    }
+                    continue;
+                }
+
+                stack.pop();
+                if let Some(&(parent, _, _)) = stack.last() {
+                    let low_parent = low[&parent].min(low[&v]);
+                    low.insert(parent, low_parent);
+                    if low[&v] > disc[&parent] {
+                        bridge_ids.insert(parent_edge.unwrap());
+                    }
+                }
+            }
+        }
+
+        self.edges_sorted()
+            .into_iter()
+            .filter(|e| bridge_ids.contains(&e.id()))
+            .collect()
+    }
+
+    /// Componentes 2-aresta-conexos, ignorando a direção das arestas.
+    ///
+    /// São os componentes que sobram depois de remover todas as pontes (ver `bridges`):
+    /// dentro de cada um, os vértices continuam conectados após a remoção de qualquer aresta.
+    /// Cada componente vem em ordem crescente, e os componentes são ordenados pelo menor
+    /// vértice. Vértices isolados formam componentes unitários.
+    pub fn two_edge_connected_components(&self) -> Vec<Vec<i32>> {
+        let bridge_ids: HashSet<usize> = self.bridges().iter().map(|e| e.id()).collect();
+        let adj = self.undirected_adjacency();
+        let mut visited: HashSet<i32> = HashSet::with_capacity(adj.len());
+        let mut components = Vec::new();
+
+        for root in self.vertices_sorted() {
+            if !visited.insert(root) {
+                continue;
+            }
+            let mut component = vec![root];
+            let mut queue = VecDeque::from([root]);
+            while let Some(v) = queue.pop_front() {
+                for &(w, id) in adj[&v].iter() {
+                    if !bridge_ids.contains(&id) && visited.insert(w) {
+                        component.push(w);
+                        queue.push_back(w);
+                    }
+                }
+            }
+            component.sort();
+            components.push(component);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn two_cycles_joined_by_a_bridge() {
+        // Ciclos {1, 2, 3} e {4, 5, 6} ligados pela ponte 3 -> 4; 7 pendurado em 6
+        let mut g = DiGraph::from_edge_list(&[
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (6, 7),
+            (8, 8),
+        ]);
+        let bridges: Vec<(i32, i32)> = g.bridges().iter().map(|e| e.v_w()).collect();
+        assert_eq!(bridges, vec![(3, 4), (6, 7)]);
+        assert_eq!(
+            g.two_edge_connected_components(),
+            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7], vec![8]]
+        );
+
+        // Uma aresta paralela à ponte deixa de ser ponte
+        g.add_edge(Edge::new(4, 3));
+        let bridges: Vec<(i32, i32)> = g.bridges().iter().map(|e| e.v_w()).collect();
+        assert_eq!(bridges, vec![(6, 7)]);
+        assert_eq!(g.two_edge_connected_components()[0], vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn bridges_and_components_match_edge_removal() {
+        let mut rng = StdRng::seed_from_u64(434);
+        for _ in 0..100 {
+            let edges: Vec<(i32, i32)> = (0..9)
+                .map(|_| (rng.gen_range(0..7), rng.gen_range(0..7)))
+                .collect();
+            let g = DiGraph::from_edge_list(&edges);
+            let bridge_ids: HashSet<usize> = g.bridges().iter().map(|e| e.id()).collect();
+
+            // Grafos sem cada uma das arestas
+            let without: Vec<(Edge, DiGraph)> = g
+                .all_edges()
+                .into_iter()
+                .map(|e| {
+                    let mut h = g.clone();
+                    h.retain_edges(|other| other.id() != e.id());
+                    (e, h)
+                })
+                .collect();
+            for (e, h) in without.iter() {
+                let (v, w) = e.v_w();
+                let separated = !h.component_of(v).unwrap().contains(&w);
+                assert_eq!(bridge_ids.contains(&e.id()), separated);
+            }
+
+            // Dois vértices ficam juntos se continuam conectados sem qualquer aresta
+            let keys = g.vertices_sorted();
+            let components = g.two_edge_connected_components();
+            for &u in keys.iter() {
+                for &v in keys.iter() {
+                    let together = g.component_of(u).unwrap().contains(&v)
+                        && without
+                            .iter()
+                            .all(|(_, h)| h.component_of(u).unwrap().contains(&v));
+                    let same = components.iter().any(|c| c.contains(&u) && c.contains(&v));
+                    assert_eq!(same, together);
+                }
+            }
+        }
+    }
+}
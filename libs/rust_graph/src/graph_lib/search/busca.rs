@@ -1,6 +1,7 @@
 use std::{
-    borrow::BorrowMut,
+    borrow::{BorrowMut, Cow},
     cell::{Ref, RefCell},
+    cmp::{Ordering, Reverse},
     collections::HashMap,
     rc::Rc,
 };
@@ -112,6 +113,23 @@ impl DfsStruct {
         self.tempo_termino.get(&vertice_key).is_some()
     }
 
+    /// Tempo de descoberta de um vértice, `None` se ele não foi visitado
+    pub fn discovery_time(&self, key: i32) -> Option<u32> {
+        self.tempo_descoberta.get(&key).map(|&t| t as u32)
+    }
+
+    /// Tempo de término de um vértice, `None` se ele não foi explorado
+    pub fn finish_time(&self, key: i32) -> Option<u32> {
+        self.tempo_termino.get(&key).map(|&t| t as u32)
+    }
+
+    /// Pai de um vértice na floresta de busca
+    ///
+    /// `None` se o vértice é raiz de uma árvore ou não foi visitado
+    pub fn father(&self, key: i32) -> Option<i32> {
+        self.fathers.get(&key).copied()
+    }
+
     pub fn classificate_aresta(&mut self, aresta: &Edge, class: EdgeClassification) {
         self.class_arestas.insert(aresta.clone(), class.clone());
 
@@ -160,6 +178,38 @@ impl DfsStruct {
     }
 }
 
+/// Ordem em que as arestas de um vértice são exploradas na busca em profundidade
+///
+/// `Ascending`: pela chave de destino crescente (padrão)
+///
+/// `Descending`: pela chave de destino decrescente
+///
+/// `Custom`: comparador definido pelo usuário, aplicado sobre a ordem crescente (ordenação estável)
+#[allow(unused)]
+pub enum NeighborOrder {
+    Ascending,
+    Descending,
+    Custom(EdgeComparator),
+}
+
+/// Comparador de arestas usado por `NeighborOrder::Custom`
+pub type EdgeComparator = Box<dyn Fn(&Edge, &Edge) -> Ordering>;
+
+impl NeighborOrder {
+    /// Ordena as arestas segundo o critério escolhido.
+    ///
+    /// `edges` deve vir de `Vertice::edges_sorted` para que empates sejam determinísticos.
+    pub fn sort(&self, edges: &mut [Edge]) {
+        match self {
+            NeighborOrder::Ascending => {}
+            NeighborOrder::Descending => {
+                edges.sort_by_key(|e| (Reverse(e.destiny_key()), e.id()))
+            }
+            NeighborOrder::Custom(cmp) => edges.sort_by(|a, b| cmp(a, b)),
+        }
+    }
+}
+
 pub trait DeepFirstSearch {
     fn DeepFirstSearch(&self, start_vertice: i32, data: &mut DfsStruct);
 }
@@ -167,6 +217,56 @@ pub trait DeepFirstSearch {
 /// Implementação de busca em profundidade
 impl DeepFirstSearch for DiGraph {
     fn DeepFirstSearch(&self, search_key: i32, dfs_data: &mut DfsStruct) {
+        self.deep_first_search_ordered(search_key, dfs_data, &NeighborOrder::Ascending);
+    }
+}
+
+/// Callbacks chamados durante a busca em profundidade (ver `DiGraph::dfs_visit`)
+///
+/// Todos os métodos têm implementação vazia, então basta sobrescrever os necessários.
+#[allow(unused)]
+pub trait DfsVisitor {
+    /// Chamado quando um vértice é descoberto
+    fn on_discover(&mut self, key: i32) {}
+
+    /// Chamado quando todos os vizinhos de um vértice foram explorados
+    fn on_finish(&mut self, key: i32) {}
+
+    /// Chamado quando uma aresta é classificada
+    fn on_edge(&mut self, edge: &Edge, classification: &EdgeClassification) {}
+}
+
+/// Visitante que não faz nada, usado pelas buscas sem callbacks
+impl DfsVisitor for () {}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Busca em profundidade a partir de `search_key` explorando as arestas na ordem `order`.
+    pub fn deep_first_search_ordered(
+        &self,
+        search_key: i32,
+        dfs_data: &mut DfsStruct,
+        order: &NeighborOrder,
+    ) {
+        self.deep_first_search_visiting(search_key, dfs_data, order, &mut ());
+    }
+
+    /// Busca em profundidade a partir de `start` chamando os callbacks de `visitor`.
+    ///
+    /// Só visita os vértices alcançáveis a partir de `start`, explorando as arestas em
+    /// ordem crescente de destino (como `dfs_search`).
+    pub fn dfs_visit(&self, start: i32, visitor: &mut impl DfsVisitor) {
+        let mut dfs_data = DfsStruct::new(self);
+        self.deep_first_search_visiting(start, &mut dfs_data, &NeighborOrder::Ascending, visitor);
+    }
+
+    fn deep_first_search_visiting(
+        &self,
+        search_key: i32,
+        dfs_data: &mut DfsStruct,
+        order: &NeighborOrder,
+        visitor: &mut impl DfsVisitor,
+    ) {
         let mut stack: Vec<i32> = Vec::new();
         stack.push(search_key);
         dfs_data.add_root(search_key);
@@ -174,18 +274,25 @@ impl DeepFirstSearch for DiGraph {
         while let Some(&vertice_key) = stack.last() {
             if !dfs_data.already_visited(vertice_key) {
                 dfs_data.start_exploring(vertice_key);
+                visitor.on_discover(vertice_key);
             } else {
             }
 
-            let arestas = self.edges_of(vertice_key);
-
-            let Some(mut arestas) = arestas else {
+            let Some(vertice) = self.get_vertice_arc(vertice_key) else {
                 dfs_data.finish_exploring(vertice_key);
+                visitor.on_finish(vertice_key);
                 stack.pop();
                 continue;
             };
-
-            arestas.sort_by(|a, b| a.destiny_key().cmp(&b.destiny_key()));
+            // Com `sort_all_edges`, a ordem crescente já está pronta no vértice
+            let arestas: Cow<[Edge]> = match (order, vertice.sorted_edges_ref()) {
+                (NeighborOrder::Ascending, Some(sorted)) => Cow::Borrowed(sorted),
+                _ => {
+                    let mut arestas = vertice.edges_sorted();
+                    order.sort(&mut arestas);
+                    Cow::Owned(arestas)
+                }
+            };
 
             let mut descobriu_vertice = false;
 
@@ -199,13 +306,13 @@ impl DeepFirstSearch for DiGraph {
                     // Não foi descoberto ainda, árvore
                     dfs_data.fathers.insert(aresta.destiny_key(), vertice_key);
                     stack.push(aresta.destiny_key()); // Empilha o vértice
-                    dfs_data.classificate_aresta(&aresta, EdgeClassification::Arvore);
+                    classify(dfs_data, visitor, aresta, EdgeClassification::Arvore);
                     descobriu_vertice = true;
                     break;
                 }
                 if !dfs_data.already_explored(aresta.destiny_key()) {
                     // Se ainda não finalizou, é retorno
-                    dfs_data.classificate_aresta(&aresta, EdgeClassification::Retorno);
+                    classify(dfs_data, visitor, aresta, EdgeClassification::Retorno);
                 } else {
                     let vertice_descoberta =
                         dfs_data.tempo_descoberta.get(&vertice_key).unwrap_or(&-1);
@@ -215,18 +322,149 @@ impl DeepFirstSearch for DiGraph {
                         .unwrap_or(&-1);
                     if vertice_descoberta < destino_descoberta {
                         // Se já finalizou a busca, mas ele é mais novo que o vertice_key, é avanço
-                        dfs_data.classificate_aresta(&aresta, EdgeClassification::Avanco);
+                        classify(dfs_data, visitor, aresta, EdgeClassification::Avanco);
                     } else {
                         // Se já finalizou a busca, mas ele é mais velho que o vertice_key, é cruzamento
-                        dfs_data.classificate_aresta(&aresta, EdgeClassification::Cruzamento);
+                        classify(dfs_data, visitor, aresta, EdgeClassification::Cruzamento);
                     }
                 }
             }
 
             if !descobriu_vertice {
                 dfs_data.finish_exploring(vertice_key);
+                visitor.on_finish(vertice_key);
                 stack.pop();
             }
         }
     }
 }
+
+/// Classifica a aresta e avisa o visitante
+fn classify(
+    dfs_data: &mut DfsStruct,
+    visitor: &mut impl DfsVisitor,
+    aresta: &Edge,
+    class: EdgeClassification,
+) {
+    visitor.on_edge(aresta, &class);
+    dfs_data.classificate_aresta(aresta, class);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn discovery_order(dfs: &DfsStruct, keys: &[i32]) -> Vec<i32> {
+        let mut order = keys.to_vec();
+        order.sort_by_key(|&k| dfs.discovery_time(k));
+        order
+    }
+
+    #[test]
+    fn ascending_and_descending_discover_in_opposite_order() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        let keys = [0, 1, 2, 3];
+
+        let ascending = g.dfs_search_with(0, NeighborOrder::Ascending);
+        let descending = g.dfs_search_with(0, NeighborOrder::Descending);
+        assert_eq!(discovery_order(&ascending, &keys), vec![0, 1, 3, 2]);
+        assert_eq!(discovery_order(&descending, &keys), vec![0, 2, 3, 1]);
+        assert_ne!(ascending.discovery_time(1), descending.discovery_time(1));
+
+        // 3 é descoberto pelo primeiro ramo explorado
+        assert_eq!(ascending.father(3), Some(1));
+        assert_eq!(descending.father(3), Some(2));
+
+        // Ascending é a ordem padrão de `dfs_search`
+        let default = g.dfs_search(0);
+        assert_eq!(default.tempo_descoberta, ascending.tempo_descoberta);
+        assert_eq!(default.tempo_termino, ascending.tempo_termino);
+    }
+
+    #[test]
+    fn custom_order_uses_the_comparator() {
+        // Explora primeiro as arestas mais leves
+        let g = DiGraph::from_weighted_edges(&[(0, 1, 9), (0, 2, 1), (0, 3, 5)]);
+        let by_weight = NeighborOrder::Custom(Box::new(|a, b| a.weight().cmp(&b.weight())));
+        let dfs = g.dfs_search_with(0, by_weight);
+        assert_eq!(discovery_order(&dfs, &[0, 1, 2, 3]), vec![0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn discovery_precedes_finish_and_intervals_nest() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (0, 3), (3, 2), (4, 0)]);
+        let dfs = g.dfs_search(0);
+        for v in 0..5 {
+            assert!(dfs.discovery_time(v).unwrap() < dfs.finish_time(v).unwrap());
+        }
+        // O intervalo de cada filho fica dentro do intervalo do pai
+        for v in 1..5 {
+            if let Some(father) = dfs.father(v) {
+                assert!(dfs.discovery_time(father) < dfs.discovery_time(v));
+                assert!(dfs.finish_time(v) < dfs.finish_time(father));
+            }
+        }
+        assert_eq!(dfs.father(1), Some(0));
+        assert_eq!(dfs.father(2), Some(1));
+        assert_eq!(dfs.father(3), Some(0));
+        // Raízes não têm pai; 4 é raiz da segunda árvore
+        assert_eq!(dfs.father(0), None);
+        assert_eq!(dfs.father(4), None);
+        assert_eq!(dfs.discovery_time(9), None);
+        assert_eq!(dfs.finish_time(9), None);
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        discovered: Vec<i32>,
+        finished: Vec<i32>,
+        edges: Vec<((i32, i32), &'static str)>,
+    }
+
+    impl DfsVisitor for Recorder {
+        fn on_discover(&mut self, key: i32) {
+            self.discovered.push(key);
+        }
+
+        fn on_finish(&mut self, key: i32) {
+            self.finished.push(key);
+        }
+
+        fn on_edge(&mut self, edge: &Edge, classification: &EdgeClassification) {
+            let name = match classification {
+                EdgeClassification::Arvore => "arvore",
+                EdgeClassification::Retorno => "retorno",
+                EdgeClassification::Avanco => "avanco",
+                EdgeClassification::Cruzamento => "cruzamento",
+            };
+            self.edges.push((edge.v_w(), name));
+        }
+    }
+
+    #[test]
+    fn visitor_records_discovery_and_classifications() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 0), (0, 2), (0, 3), (3, 1), (5, 4)]);
+        let mut recorder = Recorder::default();
+        g.dfs_visit(0, &mut recorder);
+
+        // Só a árvore de 0 é visitada
+        assert_eq!(recorder.discovered, vec![0, 1, 2, 3]);
+        assert_eq!(recorder.finished, vec![2, 1, 3, 0]);
+        let mut edges = recorder.edges;
+        edges.sort();
+        assert_eq!(
+            edges,
+            vec![
+                ((0, 1), "arvore"),
+                ((0, 2), "avanco"),
+                ((0, 3), "arvore"),
+                ((1, 2), "arvore"),
+                ((2, 0), "retorno"),
+                ((3, 1), "cruzamento"),
+            ]
+        );
+
+        // O visitante vazio compila e não faz nada
+        g.dfs_visit(0, &mut ());
+    }
+}
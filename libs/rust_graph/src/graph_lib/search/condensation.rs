@@ -0,0 +1,241 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Cria a condensação do grafo: cada componente fortemente conexo vira um vértice.
+    ///
+    /// A chave de cada componente é sua posição em `strongly_connected_components`.
+    /// Toda aresta entre componentes diferentes é mantida (com id e peso), então podem surgir
+    /// arestas paralelas. O resultado é sempre acíclico.
+    ///
+    /// Retorna o grafo condensado e o mapa vértice original -> componente.
+    pub fn condensation(&self) -> (DiGraph, HashMap<i32, i32>) {
+        let components = self.strongly_connected_components();
+        let mut component_of: HashMap<i32, i32> = HashMap::with_capacity(self.vertices_length());
+        let mut condensed = DiGraph::new_sized(components.len() as u32);
+        for (c, component) in components.iter().enumerate() {
+            condensed.add_vertice(c as i32);
+            for &v in component {
+                component_of.insert(v, c as i32);
+            }
+        }
+        for e in self.edges_sorted() {
+            let (cv, cw) = (component_of[&e.origin_key()], component_of[&e.destiny_key()]);
+            if cv != cw {
+                condensed.add_edge(e.rewired(cv, cw));
+            }
+        }
+        (condensed, component_of)
+    }
+
+    /// Caminho mais longo em qualquer grafo, mesmo com ciclos.
+    ///
+    /// Calcula o caminho de maior peso na condensação (cada componente fortemente conexo vira
+    /// um vértice) e o expande de volta para os vértices originais.
+    ///
+    /// ## Semântica dentro dos ciclos
+    /// Apenas as arestas entre componentes são otimizadas. Dentro de um componente:
+    /// - no primeiro, o caminho começa no vértice mais distante (em saltos) da saída
+    /// - nos intermediários, segue a rota com menos saltos da entrada até a saída
+    /// - no último, termina no vértice mais distante (em saltos) da entrada
+    ///
+    /// Assim nenhum vértice se repete, mas os ciclos não são percorridos por completo.
+    ///
+    /// Retorna os vértices do caminho e a soma dos pesos de todas as arestas usadas.
+    /// Um grafo vazio resulta em `(vec![], 0)`.
+    pub fn longest_path_any(&self) -> (Vec<i32>, i64) {
+        let (condensed, component_of) = self.condensation();
+        if condensed.vertices_length() == 0 {
+            return (vec![], 0);
+        }
+
+        // Ordem topológica da condensação (Kahn)
+        let mut in_degree: HashMap<i32, usize> = condensed
+            .iter_vertices()
+            .map(|v| (v.key(), v.in_degree()))
+            .collect();
+        let mut queue: VecDeque<i32> = condensed
+            .vertices_sorted()
+            .into_iter()
+            .filter(|v| in_degree[v] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(condensed.vertices_length());
+        while let Some(c) = queue.pop_front() {
+            order.push(c);
+            for e in condensed.get_vertice_arc(c).unwrap().edges_sorted() {
+                let degree = in_degree.get_mut(&e.destiny_key()).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(e.destiny_key());
+                }
+            }
+        }
+
+        // Programação dinâmica: melhor peso terminando em cada componente e a aresta usada
+        let mut best: HashMap<i32, i64> = order.iter().map(|&c| (c, 0)).collect();
+        let mut via: HashMap<i32, Edge> = HashMap::new();
+        for &c in order.iter() {
+            for e in condensed.get_vertice_arc(c).unwrap().edges_sorted() {
+                let candidate = best[&c] + e.weight() as i64;
+                if candidate > best[&e.destiny_key()] {
+                    best.insert(e.destiny_key(), candidate);
+                    via.insert(e.destiny_key(), e);
+                }
+            }
+        }
+        let mut last = order[0];
+        for &c in order.iter() {
+            if best[&c] > best[&last] {
+                last = c;
+            }
+        }
+
+        // Arestas originais entre componentes, do fim para o começo
+        let original: HashMap<usize, Edge> = self.all_edges().into_iter().map(|e| (e.id(), e)).collect();
+        let mut bridges: Vec<Edge> = Vec::new();
+        let mut c = last;
+        while let Some(e) = via.get(&c) {
+            bridges.push(original[&e.id()].clone());
+            c = e.origin_key();
+        }
+        bridges.reverse();
+
+        let members = |c: i32| -> HashSet<i32> {
+            component_of.iter().filter(|&(_, &cc)| cc == c).map(|(&v, _)| v).collect()
+        };
+
+        let mut path: Vec<i32> = Vec::new();
+        let Some(first) = bridges.first() else {
+            // Sem arestas entre componentes: percorre o maior trecho do único componente
+            let component = members(last);
+            let start = *component.iter().min().unwrap();
+            let end = self.farthest_within(start, &component, false);
+            let path = self.hop_path_within(start, end, &component);
+            let cost = self.path_cost(&path);
+            return (path, cost);
+        };
+
+        let first_component = members(component_of[&first.origin_key()]);
+        let start = self.farthest_within(first.origin_key(), &first_component, true);
+        path.extend(self.hop_path_within(start, first.origin_key(), &first_component));
+
+        for (i, bridge) in bridges.iter().enumerate() {
+            let entry = bridge.destiny_key();
+            let component = members(component_of[&entry]);
+            let exit = match bridges.get(i + 1) {
+                Some(next) => next.origin_key(),
+                None => self.farthest_within(entry, &component, false),
+            };
+            path.extend(self.hop_path_within(entry, exit, &component));
+        }
+        let cost = self.path_cost(&path);
+        (path, cost)
+    }
+
+    /// Vértice de `allowed` mais distante (em saltos) de `from`, seguindo as arestas
+    /// no sentido direto ou, se `backwards`, no sentido inverso. Empates ficam com a menor chave.
+    fn farthest_within(&self, from: i32, allowed: &HashSet<i32>, backwards: bool) -> i32 {
+        let mut dist: HashMap<i32, usize> = HashMap::from([(from, 0)]);
+        let mut queue = VecDeque::from([from]);
+        let mut farthest = (0, from);
+        let predecessors = if backwards {
+            self.all_predecessors()
+        } else {
+            HashMap::new()
+        };
+        while let Some(v) = queue.pop_front() {
+            let mut next: Vec<i32> = if backwards {
+                predecessors[&v].clone()
+            } else {
+                self.get_sucessor(v).unwrap_or_default()
+            };
+            next.sort();
+            for w in next {
+                if allowed.contains(&w) && !dist.contains_key(&w) {
+                    let d = dist[&v] + 1;
+                    dist.insert(w, d);
+                    if d > farthest.0 || (d == farthest.0 && w < farthest.1) {
+                        farthest = (d, w);
+                    }
+                    queue.push_back(w);
+                }
+            }
+        }
+        farthest.1
+    }
+
+    /// Caminho com menos saltos de `from` até `to` usando apenas vértices de `allowed`.
+    fn hop_path_within(&self, from: i32, to: i32, allowed: &HashSet<i32>) -> Vec<i32> {
+        let mut father: HashMap<i32, i32> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        let mut visited = HashSet::from([from]);
+        while let Some(v) = queue.pop_front() {
+            if v == to {
+                break;
+            }
+            let mut next = self.get_sucessor(v).unwrap_or_default();
+            next.sort();
+            for w in next {
+                if allowed.contains(&w) && visited.insert(w) {
+                    father.insert(w, v);
+                    queue.push_back(w);
+                }
+            }
+        }
+        let mut path = vec![to];
+        let mut current = to;
+        while let Some(&f) = father.get(&current) {
+            path.push(f);
+            current = f;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Soma dos pesos ao longo de uma sequência de vértices, usando a aresta mais pesada
+    /// entre cada par consecutivo.
+    fn path_cost(&self, path: &[i32]) -> i64 {
+        path.windows(2)
+            .filter_map(|pair| {
+                self.get_edges(pair[0], pair[1])?
+                    .iter()
+                    .map(|e| e.weight() as i64)
+                    .max()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_path_any_through_a_cycle_and_a_tail() {
+        // Ciclo 1 -> 2 -> 3 -> 1 com a cauda 3 -> 4 -> 5
+        let g =
+            DiGraph::from_weighted_edges(&[(1, 2, 1), (2, 3, 1), (3, 1, 1), (3, 4, 5), (4, 5, 2)]);
+        let (path, weight) = g.longest_path_any();
+        // Entra no ciclo pelo vértice mais distante da saída (3) e não repete vértices
+        assert_eq!(path, vec![1, 2, 3, 4, 5]);
+        assert_eq!(weight, 9);
+        assert_eq!(DiGraph::new().longest_path_any(), (vec![], 0));
+    }
+
+    #[test]
+    fn condensation_maps_each_scc_to_one_vertex() {
+        let g = DiGraph::from_edge_list(&[(1, 2), (2, 1), (2, 3), (3, 4), (4, 3), (1, 4), (5, 5)]);
+        let (condensed, component_of) = g.condensation();
+        assert_eq!(condensed.vertices_length(), 3);
+        assert_eq!(component_of[&1], component_of[&2]);
+        assert_eq!(component_of[&3], component_of[&4]);
+        assert_ne!(component_of[&1], component_of[&3]);
+        // As duas arestas entre {1, 2} e {3, 4} viram paralelas; o laço em 5 some
+        let (a, b) = (component_of[&1], component_of[&3]);
+        assert_eq!(condensed.get_edges(a, b).unwrap().len(), 2);
+        assert_eq!(condensed.edges_length(), 2);
+        assert!(condensed.topological_iter().is_ok());
+    }
+}
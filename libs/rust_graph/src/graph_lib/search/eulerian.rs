@@ -0,0 +1,154 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::DiGraph;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Trilha euleriana ignorando a direção das arestas: percorre cada aresta exatamente uma vez.
+    ///
+    /// Cada aresta (inclusive paralelas e laços) é percorrida individualmente. Condições:
+    /// - 0 ou 2 vértices de grau ímpar (um laço soma 2 ao grau)
+    /// - os vértices com arestas formam um único componente conexo
+    ///
+    /// Construída por Hierholzer, começando no menor vértice de grau ímpar (ou no menor vértice
+    /// com arestas) e preferindo sempre o menor vizinho. Um grafo sem arestas resulta em
+    /// `Some(vec![])`.
+    ///
+    /// `None` se não existir trilha euleriana.
+    pub fn eulerian_path_undirected(&self) -> Option<Vec<i32>> {
+        // Lista de adjacência não direcionada: (vizinho, id da aresta)
+        let mut adj: HashMap<i32, Vec<(i32, usize)>> = HashMap::new();
+        for e in self.edges_sorted() {
+            let (v, w) = e.v_w();
+            adj.entry(v).or_default().push((w, e.id()));
+            adj.entry(w).or_default().push((v, e.id()));
+        }
+        if adj.is_empty() {
+            return Some(vec![]);
+        }
+
+        let mut active: Vec<i32> = adj.keys().copied().collect();
+        active.sort();
+        let odd: Vec<i32> = active
+            .iter()
+            .copied()
+            .filter(|v| adj[v].len() % 2 == 1)
+            .collect();
+        if !odd.is_empty() && odd.len() != 2 {
+            return None;
+        }
+        let component: HashSet<i32> = self.component_of(active[0]).unwrap().into_iter().collect();
+        if active.iter().any(|v| !component.contains(v)) {
+            return None;
+        }
+
+        // Menor vizinho fica no fim para sair primeiro com `pop`
+        for list in adj.values_mut() {
+            list.sort_by(|a, b| b.cmp(a));
+        }
+        let start = odd.first().copied().unwrap_or(active[0]);
+        let mut used: HashSet<usize> = HashSet::with_capacity(self.edges_length());
+        let mut stack = vec![start];
+        let mut trail = Vec::with_capacity(self.edges_length() + 1);
+
+        while let Some(&v) = stack.last() {
+            let list = adj.get_mut(&v).unwrap();
+            while list.last().is_some_and(|(_, id)| used.contains(id)) {
+                list.pop();
+            }
+            match list.pop() {
+                Some((w, id)) => {
+                    used.insert(id);
+                    stack.push(w);
+                }
+                None => {
+                    trail.push(v);
+                    stack.pop();
+                }
+            }
+        }
+        trail.reverse();
+        Some(trail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Arestas sem direção como pares `(menor, maior)`, em ordem
+    fn undirected_edges(edges: &[(i32, i32)]) -> Vec<(i32, i32)> {
+        let mut pairs: Vec<(i32, i32)> = edges.iter().map(|&(v, w)| (v.min(w), v.max(w))).collect();
+        pairs.sort();
+        pairs
+    }
+
+    /// Existe trilha que usa todas as arestas restantes a partir de `v`? (busca exaustiva)
+    fn has_trail_from(v: i32, remaining: &mut Vec<(i32, i32)>) -> bool {
+        if remaining.is_empty() {
+            return true;
+        }
+        for i in 0..remaining.len() {
+            let (a, b) = remaining[i];
+            if a != v && b != v {
+                continue;
+            }
+            let next = if a == v { b } else { a };
+            let edge = remaining.remove(i);
+            let found = has_trail_from(next, remaining);
+            remaining.insert(i, edge);
+            if found {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn trail_between_the_two_odd_vertices() {
+        // Só 1 e 2 têm grau ímpar; 3 - 4 aparece duas vezes e 2 tem um laço
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (1, 3),
+            (3, 4),
+            (4, 3),
+            (3, 2),
+            (2, 2),
+        ];
+        let g = DiGraph::from_edge_list(&edges);
+        let trail = g.eulerian_path_undirected().unwrap();
+        assert_eq!((trail[0], *trail.last().unwrap()), (1, 2));
+        let walked: Vec<(i32, i32)> = trail.windows(2).map(|p| (p[0], p[1])).collect();
+        assert_eq!(undirected_edges(&walked), undirected_edges(&edges));
+
+        // Quatro vértices ímpares
+        assert_eq!(
+            DiGraph::from_edge_list(&[(0, 1), (2, 3)]).eulerian_path_undirected(),
+            None
+        );
+        assert_eq!(DiGraph::new().eulerian_path_undirected(), Some(vec![]));
+    }
+
+    #[test]
+    fn eulerian_path_undirected_matches_exhaustive_search() {
+        let mut rng = StdRng::seed_from_u64(410);
+        for _ in 0..300 {
+            let edges: Vec<(i32, i32)> = (0..rng.gen_range(1..7))
+                .map(|_| (rng.gen_range(0..5), rng.gen_range(0..5)))
+                .collect();
+            let g = DiGraph::from_edge_list(&edges);
+            let exists = (0..5).any(|v| has_trail_from(v, &mut edges.clone()));
+            match g.eulerian_path_undirected() {
+                Some(trail) => {
+                    assert!(exists);
+                    let walked: Vec<(i32, i32)> = trail.windows(2).map(|p| (p[0], p[1])).collect();
+                    assert_eq!(undirected_edges(&walked), undirected_edges(&edges));
+                }
+                None => assert!(!exists),
+            }
+        }
+    }
+}
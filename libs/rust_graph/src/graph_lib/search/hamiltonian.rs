@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::DiGraph;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Procura um caminho hamiltoniano: uma ordem de vértices que visita cada vértice
+    /// exatamente uma vez seguindo a direção das arestas.
+    ///
+    /// Usa backtracking, então o custo é **exponencial** no pior caso: indicado apenas para
+    /// grafos pequenos. Poda aplicada:
+    /// - mais de um vértice sem predecessores (ou sem sucessores) torna o caminho impossível
+    /// - se existir um vértice sem predecessores, só ele é testado como início
+    /// - grafos com mais de um componente fracamente conexo são descartados de imediato
+    ///
+    /// Retorna o primeiro caminho encontrado (sucessores em ordem crescente), ou `None`.
+    /// Um grafo vazio resulta em `Some(vec![])`.
+    pub fn hamiltonian_path(&self) -> Option<Vec<i32>> {
+        let vertices = self.vertices_sorted();
+        if vertices.is_empty() {
+            return Some(vec![]);
+        }
+        if self.weakly_connected_components().len() > 1 {
+            return None;
+        }
+
+        let successors: HashMap<i32, Vec<i32>> = vertices
+            .iter()
+            .map(|&v| {
+                let mut s = self.get_sucessor(v).unwrap_or_default();
+                s.retain(|&w| w != v);
+                s.sort();
+                (v, s)
+            })
+            .collect();
+        let mut has_predecessor: HashSet<i32> = HashSet::with_capacity(vertices.len());
+        for s in successors.values() {
+            has_predecessor.extend(s.iter().copied());
+        }
+
+        let sources: Vec<i32> = vertices
+            .iter()
+            .copied()
+            .filter(|v| !has_predecessor.contains(v))
+            .collect();
+        let sinks = vertices.iter().filter(|v| successors[v].is_empty()).count();
+        if sources.len() > 1 || sinks > 1 {
+            return None;
+        }
+        let starts = if sources.is_empty() { vertices.clone() } else { sources };
+
+        let mut path = Vec::with_capacity(vertices.len());
+        let mut visited = HashSet::with_capacity(vertices.len());
+        for start in starts {
+            path.push(start);
+            visited.insert(start);
+            if Self::extend_hamiltonian(&successors, vertices.len(), &mut path, &mut visited) {
+                return Some(path);
+            }
+            path.pop();
+            visited.remove(&start);
+        }
+        None
+    }
+
+    fn extend_hamiltonian(
+        successors: &HashMap<i32, Vec<i32>>,
+        len: usize,
+        path: &mut Vec<i32>,
+        visited: &mut HashSet<i32>,
+    ) -> bool {
+        if path.len() == len {
+            return true;
+        }
+        let last = *path.last().unwrap();
+        for &w in successors[&last].iter() {
+            if !visited.insert(w) {
+                continue;
+            }
+            path.push(w);
+            if Self::extend_hamiltonian(successors, len, path, visited) {
+                return true;
+            }
+            path.pop();
+            visited.remove(&w);
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn is_hamiltonian(g: &DiGraph, path: &[i32]) -> bool {
+        let mut sorted = path.to_vec();
+        sorted.sort();
+        sorted == g.vertices_sorted() && path.windows(2).all(|p| g.has_edge(p[0], p[1]))
+    }
+
+    /// Testa todas as permutações dos vértices
+    fn exists_by_permutation(g: &DiGraph, path: &mut Vec<i32>, left: &mut Vec<i32>) -> bool {
+        if left.is_empty() {
+            return true;
+        }
+        for i in 0..left.len() {
+            let v = left[i];
+            if path.last().is_some_and(|&u| !g.has_edge(u, v)) {
+                continue;
+            }
+            left.remove(i);
+            path.push(v);
+            let found = exists_by_permutation(g, path, left);
+            path.pop();
+            left.insert(i, v);
+            if found {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn hamiltonian_path_found_and_missing() {
+        // 3 -> 1 -> 4 -> 2 -> 0 passa por todos, com arestas extras para confundir
+        let g = DiGraph::from_edge_list(&[(3, 1), (1, 4), (4, 2), (2, 0), (1, 0), (3, 4), (0, 4)]);
+        let path = g.hamiltonian_path().unwrap();
+        assert!(is_hamiltonian(&g, &path));
+        assert_eq!(path[0], 3);
+
+        // Dois vértices sem predecessores: impossível
+        let star = DiGraph::from_edge_list(&[(1, 0), (2, 0), (0, 3)]);
+        assert_eq!(star.hamiltonian_path(), None);
+        // Dois componentes
+        let split = DiGraph::from_edge_list(&[(0, 1), (2, 3)]);
+        assert_eq!(split.hamiltonian_path(), None);
+        assert_eq!(DiGraph::new().hamiltonian_path(), Some(vec![]));
+    }
+
+    #[test]
+    fn hamiltonian_path_matches_permutations() {
+        let mut rng = StdRng::seed_from_u64(393);
+        for _ in 0..300 {
+            let n = rng.gen_range(1..7);
+            let mut g = DiGraph::new();
+            for v in 0..n {
+                g.add_vertice(v);
+            }
+            for _ in 0..rng.gen_range(0..15) {
+                g.add_edge(Edge::new(rng.gen_range(0..n), rng.gen_range(0..n)));
+            }
+            let expected = exists_by_permutation(&g, &mut Vec::new(), &mut g.vertices_sorted());
+            match g.hamiltonian_path() {
+                Some(path) => assert!(expected && is_hamiltonian(&g, &path)),
+                None => assert!(!expected),
+            }
+        }
+    }
+}
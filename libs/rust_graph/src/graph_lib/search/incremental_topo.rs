@@ -0,0 +1,230 @@
+use std::collections::{HashMap, HashSet};
+
+use super::topological::CycleError;
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+/// Erro de uma aresta que fecharia o ciclo `path` (caminho de `v` até `u`)
+fn closing_cycle(path: Vec<i32>) -> CycleError {
+    CycleError {
+        remaining: path.clone(),
+        cycle: Some(path),
+    }
+}
+
+/// Ordem topológica mantida enquanto arestas são inseridas (Pearce-Kelly).
+///
+/// Guarda o grafo, a ordem (`order[posição] = chave`) e a posição de cada vértice.
+/// Uma aresta `u -> v` que já respeita a ordem não mexe em nada; caso contrário, só os
+/// vértices com posição entre `v` e `u` que alcançam `u` ou são alcançados por `v` são
+/// reposicionados, em vez de recalcular a ordem inteira.
+///
+/// Suposição: apenas inserções, e o grafo não é alterado por outro caminho.
+pub struct IncrementalTopo {
+    graph: DiGraph,
+    order: Vec<i32>,
+    position: HashMap<i32, usize>,
+}
+
+#[allow(unused)]
+impl IncrementalTopo {
+    /// Calcula a ordem inicial (Kahn, menor chave primeiro).
+    ///
+    /// `CycleError` se o grafo já tiver ciclo.
+    pub fn new(graph: DiGraph) -> Result<IncrementalTopo, CycleError> {
+        let order: Vec<i32> = graph.topological_iter()?.collect();
+        let position = order.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+        Ok(IncrementalTopo {
+            graph,
+            order,
+            position,
+        })
+    }
+
+    pub fn graph(&self) -> &DiGraph {
+        &self.graph
+    }
+
+    /// Ordem topológica atual.
+    pub fn order(&self) -> &[i32] {
+        &self.order
+    }
+
+    /// Posição do vértice na ordem atual. `None` se o vértice não existir.
+    pub fn position(&self, key: i32) -> Option<usize> {
+        self.position.get(&key).copied()
+    }
+
+    /// Devolve o grafo com as arestas inseridas.
+    pub fn into_graph(self) -> DiGraph {
+        self.graph
+    }
+
+    /// Adiciona um vértice isolado no fim da ordem (nada muda se ele já existir).
+    pub fn add_vertice(&mut self, key: i32) {
+        if self.position.contains_key(&key) {
+            return;
+        }
+        self.graph.add_vertice(key);
+        self.position.insert(key, self.order.len());
+        self.order.push(key);
+    }
+
+    /// Insere a aresta `u -> v` (peso 1) mantendo a ordem topológica.
+    /// Vértices que não existirem são criados no fim da ordem.
+    ///
+    /// `CycleError` se a aresta fechar um ciclo; nesse caso o grafo e a ordem ficam intactos,
+    /// e `cycle` (assim como `remaining`) traz o caminho de `v` até `u` que a aresta fecharia
+    /// (`[u]` para um laço).
+    pub fn add_edge(&mut self, u: i32, v: i32) -> Result<(), CycleError> {
+        if u == v {
+            return Err(closing_cycle(vec![u]));
+        }
+        self.add_vertice(u);
+        self.add_vertice(v);
+
+        let (lower, upper) = (self.position[&v], self.position[&u]);
+        if lower > upper {
+            self.graph.add_edge(Edge::new(u, v));
+            return Ok(());
+        }
+
+        let forward = self.forward_window(v, u, upper)?;
+        let backward = self.backward_window(u, lower);
+        self.reorder(backward, forward);
+        self.graph.add_edge(Edge::new(u, v));
+        Ok(())
+    }
+
+    /// Vértices alcançados por `start` com posição até `upper`.
+    /// `CycleError` (com o caminho `start .. target`) se `target` for alcançado.
+    fn forward_window(
+        &self,
+        start: i32,
+        target: i32,
+        upper: usize,
+    ) -> Result<Vec<i32>, CycleError> {
+        let mut parent: HashMap<i32, i32> = HashMap::new();
+        let mut visited: HashSet<i32> = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(x) = stack.pop() {
+            for e in self.graph.get_vertice_arc(x).unwrap().edges_vec_ref() {
+                let w = e.destiny_key();
+                if w == target {
+                    let mut cycle = vec![target, x];
+                    let mut current = x;
+                    while let Some(&p) = parent.get(&current) {
+                        cycle.push(p);
+                        current = p;
+                    }
+                    cycle.reverse();
+                    return Err(closing_cycle(cycle));
+                }
+                if self.position[&w] < upper && visited.insert(w) {
+                    parent.insert(w, x);
+                    stack.push(w);
+                }
+            }
+        }
+        Ok(visited.into_iter().collect())
+    }
+
+    /// Vértices que alcançam `start` com posição a partir de `lower`.
+    fn backward_window(&self, start: i32, lower: usize) -> Vec<i32> {
+        let mut visited: HashSet<i32> = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(x) = stack.pop() {
+            for e in self.graph.get_vertice_arc(x).unwrap().back_edges_vec_ref() {
+                let w = e.origin_key();
+                if self.position[&w] > lower && visited.insert(w) {
+                    stack.push(w);
+                }
+            }
+        }
+        visited.into_iter().collect()
+    }
+
+    /// Redistribui as posições ocupadas pelos dois conjuntos: primeiro os que alcançam `u`,
+    /// depois os alcançados por `v`, cada grupo mantendo sua ordem relativa.
+    fn reorder(&mut self, mut backward: Vec<i32>, mut forward: Vec<i32>) {
+        backward.sort_by_key(|k| self.position[k]);
+        forward.sort_by_key(|k| self.position[k]);
+
+        let mut slots: Vec<usize> = backward
+            .iter()
+            .chain(forward.iter())
+            .map(|k| self.position[k])
+            .collect();
+        slots.sort();
+
+        for (key, slot) in backward.into_iter().chain(forward).zip(slots) {
+            self.order[slot] = key;
+            self.position.insert(key, slot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    use super::*;
+
+    fn assert_valid(topo: &IncrementalTopo) {
+        assert_eq!(topo.order().len(), topo.graph().vertices_length());
+        for e in topo.graph().all_edges() {
+            let (v, w) = e.v_w();
+            assert!(topo.position(v).unwrap() < topo.position(w).unwrap());
+        }
+        for (i, &key) in topo.order().iter().enumerate() {
+            assert_eq!(topo.position(key), Some(i));
+        }
+    }
+
+    #[test]
+    fn order_stays_valid_for_several_insertion_orders() {
+        let mut rng = StdRng::seed_from_u64(441);
+        // DAG escondido atrás de uma permutação aleatória das chaves
+        let mut hidden: Vec<i32> = (0..20).collect();
+        hidden.shuffle(&mut rng);
+        let mut edges = Vec::new();
+        for _ in 0..80 {
+            let (a, b) = (rng.gen_range(0..20), rng.gen_range(0..20));
+            if a < b {
+                edges.push((hidden[a], hidden[b]));
+            }
+        }
+        for _ in 0..20 {
+            edges.shuffle(&mut rng);
+            let mut topo = IncrementalTopo::new(DiGraph::new()).unwrap();
+            for &(u, v) in edges.iter() {
+                topo.add_edge(u, v).unwrap();
+                assert_valid(&topo);
+            }
+            assert_eq!(topo.graph().edges_length(), edges.len());
+        }
+    }
+
+    #[test]
+    fn edge_closing_a_cycle_is_rejected_with_the_path() {
+        let mut topo = IncrementalTopo::new(DiGraph::from_edge_list(&[(3, 4)])).unwrap();
+        topo.add_edge(1, 2).unwrap();
+        topo.add_edge(4, 1).unwrap();
+        assert_valid(&topo);
+
+        let err = topo.add_edge(2, 3).unwrap_err();
+        assert_eq!(err.cycle, Some(vec![3, 4, 1, 2]));
+        assert_eq!(err.remaining, vec![3, 4, 1, 2]);
+        assert_eq!(topo.graph().edges_length(), 3);
+        assert_valid(&topo);
+        assert_eq!(topo.add_edge(5, 5).unwrap_err().cycle, Some(vec![5]));
+    }
+
+    #[test]
+    fn new_rejects_cyclic_graph() {
+        let Err(err) = IncrementalTopo::new(DiGraph::from_edge_list(&[(1, 2), (2, 1)])) else {
+            panic!("o grafo tem ciclo");
+        };
+        assert_eq!(err.remaining, vec![1, 2]);
+        assert_eq!(err.cycle, None);
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use crate::DiGraph;
+
+/// Pesos (ordenados) das arestas de cada par `(origem, destino)`, por índice de vértice
+type PairWeights = HashMap<(usize, usize), Vec<i32>>;
+
+/// Grafo reindexado para o casamento: chaves, graus e arestas por par
+struct IsoData {
+    keys: Vec<i32>,
+    degrees: Vec<(usize, usize)>,
+    pairs: PairWeights,
+}
+
+impl IsoData {
+    fn new(g: &DiGraph) -> IsoData {
+        let keys = g.vertices_sorted();
+        let index: HashMap<i32, usize> = keys.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+        let degrees = keys
+            .iter()
+            .map(|&k| {
+                let v = g.get_vertice_arc(k).unwrap();
+                (v.in_degree(), v.out_degree())
+            })
+            .collect();
+        let mut pairs: PairWeights = HashMap::new();
+        for e in g.all_edges() {
+            let pair = (index[&e.origin_key()], index[&e.destiny_key()]);
+            pairs.entry(pair).or_default().push(e.weight());
+        }
+        for weights in pairs.values_mut() {
+            weights.sort();
+        }
+        IsoData { keys, degrees, pairs }
+    }
+
+    /// Compara as arestas de `a -> b` aqui com as de `x -> y` em `other`
+    fn same_pair(
+        &self,
+        a: usize,
+        b: usize,
+        other: &IsoData,
+        x: usize,
+        y: usize,
+        weights: bool,
+    ) -> bool {
+        let empty = Vec::new();
+        let left = self.pairs.get(&(a, b)).unwrap_or(&empty);
+        let right = other.pairs.get(&(x, y)).unwrap_or(&empty);
+        if weights {
+            left == right
+        } else {
+            left.len() == right.len()
+        }
+    }
+}
+
+/// Estende o mapeamento parcial `mapping` (índice em `a` -> índice em `b`) ao vértice `order[depth]`.
+fn extend_mapping(
+    a: &IsoData,
+    b: &IsoData,
+    order: &[usize],
+    depth: usize,
+    mapping: &mut Vec<Option<usize>>,
+    used: &mut Vec<bool>,
+    weights: bool,
+) -> bool {
+    let Some(&v) = order.get(depth) else {
+        return true;
+    };
+    for x in 0..b.keys.len() {
+        if used[x] || a.degrees[v] != b.degrees[x] {
+            continue;
+        }
+        // As arestas entre `v` e os vértices já mapeados (e o laço em `v`) precisam coincidir
+        let consistent = a.same_pair(v, v, b, x, x, weights)
+            && order[..depth].iter().all(|&u| {
+                let y = mapping[u].unwrap();
+                a.same_pair(u, v, b, y, x, weights) && a.same_pair(v, u, b, x, y, weights)
+            });
+        if !consistent {
+            continue;
+        }
+        mapping[v] = Some(x);
+        used[x] = true;
+        if extend_mapping(a, b, order, depth + 1, mapping, used, weights) {
+            return true;
+        }
+        mapping[v] = None;
+        used[x] = false;
+    }
+    false
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Verifica se os grafos são isomorfos, sem supor que as chaves correspondem.
+    ///
+    /// Busca com retrocesso no estilo VF2: cada vértice só é casado com vértices de mesmo
+    /// grau de entrada e de saída, e as arestas (com direção e multiplicidade) entre os
+    /// vértices já casados precisam coincidir. Pesos são ignorados
+    /// (ver `is_isomorphic_with_weights`).
+    ///
+    /// O pior caso é exponencial: use apenas com grafos pequenos.
+    pub fn is_isomorphic_to(&self, other: &DiGraph) -> bool {
+        self.isomorphic(other, false)
+    }
+
+    /// Como `is_isomorphic_to`, mas cada par de vértices casados precisa ter arestas com
+    /// os mesmos pesos.
+    pub fn is_isomorphic_with_weights(&self, other: &DiGraph) -> bool {
+        self.isomorphic(other, true)
+    }
+
+    fn isomorphic(&self, other: &DiGraph, weights: bool) -> bool {
+        if self.vertices_length() != other.vertices_length()
+            || self.edges_length() != other.edges_length()
+        {
+            return false;
+        }
+        let a = IsoData::new(self);
+        let b = IsoData::new(other);
+
+        let mut degrees_a = a.degrees.clone();
+        let mut degrees_b = b.degrees.clone();
+        degrees_a.sort();
+        degrees_b.sort();
+        if degrees_a != degrees_b {
+            return false;
+        }
+
+        // Vértices de grau alto primeiro: têm menos candidatos e podam a busca mais cedo
+        let mut order: Vec<usize> = (0..a.keys.len()).collect();
+        order.sort_by_key(|&v| std::cmp::Reverse(a.degrees[v].0 + a.degrees[v].1));
+
+        let mut mapping = vec![None; a.keys.len()];
+        let mut used = vec![false; b.keys.len()];
+        extend_mapping(&a, &b, &order, 0, &mut mapping, &mut used, weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    /// Cópia de `g` com as chaves trocadas por `relabel`
+    fn relabeled(g: &DiGraph, relabel: &HashMap<i32, i32>) -> DiGraph {
+        let mut copy = DiGraph::new();
+        for v in g.vertices_sorted() {
+            copy.add_vertice(relabel[&v]);
+        }
+        for e in g.all_edges() {
+            copy.add_edge(Edge::new_weighted(
+                relabel[&e.origin_key()],
+                relabel[&e.destiny_key()],
+                e.weight(),
+            ));
+        }
+        copy
+    }
+
+    /// Todas as permutações de `items`
+    fn permutations(items: &[i32]) -> Vec<Vec<i32>> {
+        if items.is_empty() {
+            return vec![vec![]];
+        }
+        let mut result = Vec::new();
+        for (i, &first) in items.iter().enumerate() {
+            let mut rest = items.to_vec();
+            rest.remove(i);
+            for mut tail in permutations(&rest) {
+                tail.insert(0, first);
+                result.push(tail);
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn relabeled_triangles_and_different_structures() {
+        let triangle = DiGraph::from_weighted_edges(&[(1, 2, 3), (2, 3, 4), (3, 1, 5)]);
+        let other = DiGraph::from_weighted_edges(&[(30, 10, 4), (10, 20, 5), (20, 30, 3)]);
+        assert!(triangle.is_isomorphic_to(&other));
+        assert!(triangle.is_isomorphic_with_weights(&other));
+
+        let reweighted = DiGraph::from_weighted_edges(&[(30, 10, 4), (10, 20, 5), (20, 30, 9)]);
+        assert!(triangle.is_isomorphic_to(&reweighted));
+        assert!(!triangle.is_isomorphic_with_weights(&reweighted));
+
+        // Mesmos graus (todos 1 de entrada e 1 de saída), estruturas diferentes
+        let two_cycles = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+        let hexagon = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        assert!(!two_cycles.is_isomorphic_to(&hexagon));
+
+        // Ciclo contra triângulo transitivo
+        let transitive = DiGraph::from_edge_list(&[(1, 2), (2, 3), (1, 3)]);
+        assert!(!triangle.is_isomorphic_to(&transitive));
+    }
+
+    #[test]
+    fn isomorphism_matches_brute_force_on_small_graphs() {
+        let mut rng = StdRng::seed_from_u64(430);
+        let keys = [0, 1, 2, 3, 4];
+        let all_permutations = permutations(&keys);
+        let random_graph = |rng: &mut StdRng| {
+            let mut g = DiGraph::new();
+            for v in keys {
+                g.add_vertice(v);
+            }
+            for _ in 0..6 {
+                g.add_edge(Edge::new_weighted(
+                    rng.gen_range(0..5),
+                    rng.gen_range(0..5),
+                    rng.gen_range(0..2),
+                ));
+            }
+            g
+        };
+        let signature = |g: &DiGraph| {
+            let mut edges: Vec<_> = g
+                .all_edges()
+                .iter()
+                .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+                .collect();
+            edges.sort();
+            edges
+        };
+
+        for _ in 0..200 {
+            let a = random_graph(&mut rng);
+            let b = random_graph(&mut rng);
+            let mut unweighted = false;
+            let mut weighted = false;
+            for permutation in all_permutations.iter() {
+                let relabel: HashMap<i32, i32> = keys
+                    .iter()
+                    .copied()
+                    .zip(permutation.iter().copied())
+                    .collect();
+                let moved = relabeled(&a, &relabel);
+                let (sig_moved, sig_b) = (signature(&moved), signature(&b));
+                weighted |= sig_moved == sig_b;
+                let strip =
+                    |s: &[(i32, i32, i32)]| s.iter().map(|&(v, w, _)| (v, w)).collect::<Vec<_>>();
+                let (mut left, mut right) = (strip(&sig_moved), strip(&sig_b));
+                left.sort();
+                right.sort();
+                unweighted |= left == right;
+            }
+            assert_eq!(a.is_isomorphic_to(&b), unweighted);
+            assert_eq!(a.is_isomorphic_with_weights(&b), weighted);
+
+            // Uma cópia com chaves embaralhadas é sempre isomorfa
+            let mut shuffled = keys.to_vec();
+            shuffled.shuffle(&mut rng);
+            let relabel: HashMap<i32, i32> = keys
+                .iter()
+                .copied()
+                .zip(shuffled.into_iter().map(|k| k * 7))
+                .collect();
+            assert!(a.is_isomorphic_with_weights(&relabeled(&a, &relabel)));
+        }
+    }
+}
@@ -39,6 +39,19 @@ impl ConexComponents{
     pub fn clone_components(&self)->Vec<Rc<RefCell<DiGraph>>> {
         self.components.clone()
     }
+
+    /// Retorna as chaves dos vértices de cada componente, em ordem crescente.
+    ///
+    /// Os componentes são ordenados pelo seu menor vértice.
+    pub fn vertex_sets(&self) -> Vec<Vec<i32>> {
+        let mut sets: Vec<Vec<i32>> = self
+            .components
+            .iter()
+            .map(|component| component.borrow().vertices_sorted())
+            .collect();
+        sets.sort();
+        sets
+    }
     
 }
 
@@ -52,7 +65,8 @@ pub trait Kosaraju {
 impl Kosaraju for DiGraph {
     fn conex_components(&self) -> ConexComponents {
         let t_graph = self.transpose();
-        let first_dfs_data = t_graph.dfs_search(1);
+        let start = t_graph.vertices_sorted().first().copied().unwrap_or(-1);
+        let first_dfs_data = t_graph.dfs_search(start);
         let mut vertices_queue: Vec<(i32, i32)> =
             first_dfs_data.tempo_termino.into_iter().collect();
         vertices_queue.sort_by(|a, b| b.1.cmp(&a.1)); // Ordenar decrescente por tempo de término
@@ -69,3 +83,62 @@ impl Kosaraju for DiGraph {
     }
 }
 
+#[allow(unused)]
+impl DiGraph {
+    /// Retorna os componentes fortemente conexos (Kosaraju) como conjuntos de chaves.
+    ///
+    /// Cada componente vem em ordem crescente e os componentes são ordenados pelo menor vértice.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<i32>> {
+        self.conex_components().vertex_sets()
+    }
+
+    /// Verifica se `u` e `v` estão no mesmo componente fortemente conexo, isto é,
+    /// se um alcança o outro nos dois sentidos.
+    ///
+    /// Usa duas buscas (`reaches`) em vez de calcular todos os componentes.
+    /// `false` se algum dos vértices não existir.
+    pub fn in_same_scc(&self, u: i32, v: i32) -> bool {
+        self.vertice_exists(u) && self.vertice_exists(v) && self.reaches(u, v) && self.reaches(v, u)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strongly_connected_components_without_vertex_one() {
+        // Sem o vértice 1 a primeira busca precisa começar em outra chave
+        let mut g =
+            DiGraph::from_edge_list(&[(10, 11), (11, 12), (12, 10), (12, 13), (13, 14), (14, 13)]);
+        g.add_vertice(20);
+        assert_eq!(
+            g.strongly_connected_components(),
+            vec![vec![10, 11, 12], vec![13, 14], vec![20]]
+        );
+    }
+
+    #[test]
+    fn in_same_scc_requires_both_directions() {
+        let mut g =
+            DiGraph::from_edge_list(&[(10, 11), (11, 12), (12, 10), (12, 13), (13, 14), (14, 13)]);
+        g.add_vertice(20);
+        // 10 e 12 dividem o ciclo; 12 alcança 13, mas 13 não volta
+        assert!(g.in_same_scc(10, 12));
+        assert!(g.in_same_scc(14, 13));
+        assert!(!g.in_same_scc(12, 13));
+        assert!(!g.in_same_scc(13, 12));
+        assert!(g.in_same_scc(20, 20));
+        assert!(!g.in_same_scc(10, 99));
+
+        // Concorda com a partição completa
+        let components = g.strongly_connected_components();
+        let keys = g.vertices_sorted();
+        for &u in keys.iter() {
+            for &v in keys.iter() {
+                let same = components.iter().any(|c| c.contains(&u) && c.contains(&v));
+                assert_eq!(g.in_same_scc(u, v), same);
+            }
+        }
+    }
+}
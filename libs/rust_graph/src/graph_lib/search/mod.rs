@@ -1,2 +1,11 @@
+pub mod bipartite;
 pub mod busca;
-pub mod kosaraju;
\ No newline at end of file
+pub mod kosaraju;
+pub mod weak_components;
+pub mod condensation;
+pub mod topological;
+pub mod hamiltonian;
+pub mod eulerian;
+pub mod isomorphism;
+pub mod bridges;
+pub mod incremental_topo;
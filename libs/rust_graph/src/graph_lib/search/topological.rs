@@ -0,0 +1,644 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+use crate::DiGraph;
+
+/// Erro retornado quando uma operação exige um grafo acíclico e o grafo tem ciclo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// Vértices que não puderam ser ordenados (estão em um ciclo ou são alcançados por um)
+    pub remaining: Vec<i32>,
+    /// Caminho que forma o ciclo, quando a operação o encontra (ver `IncrementalTopo::add_edge`)
+    pub cycle: Option<Vec<i32>>,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "o grafo contém ciclo ({} vértices sem ordem topológica)",
+            self.remaining.len()
+        )
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// Iterador preguiçoso sobre uma ordem topológica (Kahn).
+///
+/// Cada vértice é produzido assim que seu grau de entrada restante chega a zero.
+/// Entre os vértices prontos, o de menor chave sai primeiro.
+pub struct TopoIter<'a> {
+    graph: &'a DiGraph,
+    in_degree: HashMap<i32, usize>,
+    ready: BinaryHeap<Reverse<i32>>,
+}
+
+impl<'a> TopoIter<'a> {
+    fn new(graph: &'a DiGraph) -> Self {
+        let in_degree: HashMap<i32, usize> = graph
+            .iter_vertices()
+            .map(|v| (v.key(), v.in_degree()))
+            .collect();
+        let ready = in_degree
+            .iter()
+            .filter(|&(_, &d)| d == 0)
+            .map(|(&v, _)| Reverse(v))
+            .collect();
+        TopoIter {
+            graph,
+            in_degree,
+            ready,
+        }
+    }
+}
+
+impl Iterator for TopoIter<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        let Reverse(v) = self.ready.pop()?;
+        for e in self.graph.get_vertice_arc(v).unwrap().edges_vec_ref() {
+            let degree = self.in_degree.get_mut(&e.destiny_key()).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                self.ready.push(Reverse(e.destiny_key()));
+            }
+        }
+        Some(v)
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Retorna um iterador que produz os vértices em ordem topológica sob demanda.
+    ///
+    /// O ciclo é detectado antes da iteração (um passe de Kahn que só conta os vértices),
+    /// então um `Ok` garante que o iterador visita todos os vértices.
+    ///
+    /// `CycleError` se o grafo tiver ciclo (laços incluídos).
+    pub fn topological_iter(&self) -> Result<TopoIter<'_>, CycleError> {
+        let mut check = TopoIter::new(self);
+        let visited = check.by_ref().count();
+        if visited < self.vertices_length() {
+            let mut remaining: Vec<i32> = check
+                .in_degree
+                .into_iter()
+                .filter(|&(_, d)| d > 0)
+                .map(|(v, _)| v)
+                .collect();
+            remaining.sort();
+            return Err(CycleError {
+                remaining,
+                cycle: None,
+            });
+        }
+        Ok(TopoIter::new(self))
+    }
+
+    /// Verifica, sem alterar o grafo, se adicionar `origin -> destiny` criaria um ciclo,
+    /// ou seja, se `destiny` já alcança `origin`. Um laço (`origin == destiny`) sempre cria.
+    ///
+    /// Útil para manter um DAG ao inserir arestas uma a uma.
+    pub fn would_create_cycle(&self, origin: i32, destiny: i32) -> bool {
+        self.reaches(destiny, origin)
+    }
+
+    /// Verifica se o grafo é acíclico (DAG).
+    ///
+    /// Busca em profundidade que para na primeira aresta de retorno, sem montar
+    /// uma ordem topológica. Um laço conta como ciclo.
+    pub fn is_dag(&self) -> bool {
+        // Vértices na pilha atual (em exploração) e vértices já finalizados
+        let mut on_stack: HashSet<i32> = HashSet::new();
+        let mut finished: HashSet<i32> = HashSet::with_capacity(self.vertices_length());
+
+        for root in self.vertices_sorted() {
+            if finished.contains(&root) {
+                continue;
+            }
+            let mut stack: Vec<(i32, Vec<i32>)> =
+                vec![(root, self.get_sucessor(root).unwrap_or_default())];
+            on_stack.insert(root);
+
+            while let Some((v, successors)) = stack.last_mut() {
+                let Some(w) = successors.pop() else {
+                    on_stack.remove(v);
+                    finished.insert(*v);
+                    stack.pop();
+                    continue;
+                };
+                if on_stack.contains(&w) {
+                    return false; // Aresta de retorno
+                }
+                if !finished.contains(&w) {
+                    on_stack.insert(w);
+                    stack.push((w, self.get_sucessor(w).unwrap_or_default()));
+                }
+            }
+        }
+        true
+    }
+
+    /// Redução transitiva: o menor conjunto de arestas com a mesma alcançabilidade.
+    ///
+    /// A aresta `u -> v` é removida quando `v` é alcançável a partir de `u` por outro caminho.
+    /// Entre arestas paralelas, só a de menor id é mantida. Todos os vértices são mantidos,
+    /// e as arestas restantes conservam id e peso.
+    ///
+    /// `CycleError` se o grafo tiver ciclo (a redução só é única em grafos acíclicos).
+    pub fn transitive_reduction(&self) -> Result<DiGraph, CycleError> {
+        let order: Vec<i32> = self.topological_iter()?.collect();
+
+        // Descendentes de cada vértice (sem ele mesmo), em ordem topológica reversa
+        let mut reach: HashMap<i32, HashSet<i32>> = HashMap::with_capacity(order.len());
+        for &v in order.iter().rev() {
+            let mut descendants = HashSet::new();
+            for w in self.get_sucessor(v).unwrap_or_default() {
+                descendants.insert(w);
+                descendants.extend(reach[&w].iter().copied());
+            }
+            reach.insert(v, descendants);
+        }
+
+        let mut reduced = DiGraph::new_sized(self.vertices_length() as u32);
+        for &u in order.iter() {
+            reduced.add_vertice(u);
+        }
+        for &u in order.iter() {
+            let successors = self.get_sucessor(u).unwrap_or_default();
+            for &v in successors.iter() {
+                let redundant = successors.iter().any(|&w| w != v && reach[&w].contains(&v));
+                if !redundant {
+                    let edges = self.get_edges(u, v).unwrap();
+                    reduced.add_edge(edges.into_iter().min_by_key(|e| e.id()).unwrap());
+                }
+            }
+        }
+        Ok(reduced)
+    }
+
+    /// Atribui a cada vértice uma camada (layout em camadas, estilo Sugiyama).
+    ///
+    /// A camada é o comprimento (em arestas) do maior caminho vindo de alguma fonte
+    /// (vértice sem predecessores), calculado em ordem topológica: fontes ficam na camada 0
+    /// e todo vértice fica abaixo de todos os seus predecessores, então não há dependências
+    /// dentro de uma mesma camada.
+    ///
+    /// `CycleError` se o grafo tiver ciclo.
+    pub fn layer_assignment(&self) -> Result<HashMap<i32, u32>, CycleError> {
+        let mut layers: HashMap<i32, u32> = HashMap::with_capacity(self.vertices_length());
+        for v in self.topological_iter()? {
+            let layer = *layers.entry(v).or_insert(0);
+            for e in self.get_vertice_arc(v).unwrap().edges_vec_ref() {
+                let next = layers.entry(e.destiny_key()).or_insert(0);
+                *next = (*next).max(layer + 1);
+            }
+        }
+        Ok(layers)
+    }
+
+    /// Quantidade de vértices distintos alcançáveis a partir de cada vértice de um DAG,
+    /// **sem contar o próprio vértice**.
+    ///
+    /// Programação dinâmica em ordem topológica reversa sobre conjuntos de bits: o conjunto de
+    /// um vértice é a união dos conjuntos dos seus sucessores (mais os próprios sucessores),
+    /// então descendentes compartilhados por vários caminhos são contados uma única vez.
+    ///
+    /// `CycleError` se o grafo tiver ciclo.
+    pub fn descendant_count(&self) -> Result<HashMap<i32, u64>, CycleError> {
+        let order: Vec<i32> = self.topological_iter()?.collect();
+        let index: HashMap<i32, usize> = order.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        let reach = self.dag_reach_words(&order, &index);
+
+        Ok(order
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (v, reach[i].iter().map(|w| w.count_ones() as u64).sum()))
+            .collect())
+    }
+
+    /// Conjunto de alcançáveis de cada vértice, como vetor de bits.
+    ///
+    /// O bit `j` do vetor de `v` indica se `v` alcança `vertices_sorted()[j]` por um caminho
+    /// com pelo menos uma aresta (fecho transitivo): o próprio vértice só aparece se estiver
+    /// em um ciclo. Em DAGs une os conjuntos dos sucessores em ordem topológica reversa;
+    /// nos demais grafos faz uma BFS por vértice.
+    pub fn reachability_bitsets(&self) -> HashMap<i32, Vec<bool>> {
+        let keys = self.vertices_sorted();
+        let index: HashMap<i32, usize> = keys.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+        let reach: Vec<Vec<u64>> = match self.topological_iter() {
+            Ok(order) => {
+                let order: Vec<i32> = order.collect();
+                // `dag_reach_words` indexa pela posição de cada vértice em `keys`
+                let mut by_key = vec![Vec::new(); keys.len()];
+                for (i, words) in self.dag_reach_words(&order, &index).into_iter().enumerate() {
+                    by_key[index[&order[i]]] = words;
+                }
+                by_key
+            }
+            Err(_) => keys.iter().map(|&v| self.bfs_reach_words(v, &index)).collect(),
+        };
+
+        keys.iter()
+            .zip(reach)
+            .map(|(&v, words)| {
+                let bits = (0..keys.len()).map(|j| words[j / 64] & (1 << (j % 64)) != 0);
+                (v, bits.collect())
+            })
+            .collect()
+    }
+
+    /// Alcançáveis de cada vértice de `order` (ordem topológica), em palavras de 64 bits.
+    ///
+    /// A posição `i` do resultado corresponde a `order[i]`, e o bit `index[w]` indica que `w`
+    /// é alcançável.
+    fn dag_reach_words(&self, order: &[i32], index: &HashMap<i32, usize>) -> Vec<Vec<u64>> {
+        let words = order.len().div_ceil(64);
+        let position: HashMap<i32, usize> = order.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+        let mut reach: Vec<Vec<u64>> = vec![vec![0; words]; order.len()];
+        for (i, &v) in order.iter().enumerate().rev() {
+            let mut bits = vec![0u64; words];
+            for w in self.get_sucessor(v).unwrap_or_default() {
+                let j = index[&w];
+                bits[j / 64] |= 1 << (j % 64);
+                for (word, other) in bits.iter_mut().zip(reach[position[&w]].iter()) {
+                    *word |= other;
+                }
+            }
+            reach[i] = bits;
+        }
+        reach
+    }
+
+    /// Alcançáveis de `source` por BFS (caminhos com pelo menos uma aresta), em palavras de 64 bits.
+    fn bfs_reach_words(&self, source: i32, index: &HashMap<i32, usize>) -> Vec<u64> {
+        let mut bits = vec![0u64; index.len().div_ceil(64)];
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            for w in self.get_sucessor(v).unwrap_or_default() {
+                let j = index[&w];
+                if bits[j / 64] & (1 << (j % 64)) == 0 {
+                    bits[j / 64] |= 1 << (j % 64);
+                    queue.push_back(w);
+                }
+            }
+        }
+        bits
+    }
+
+    /// Conta os caminhos direcionados distintos de `from` até `to` em um grafo acíclico.
+    ///
+    /// Programação dinâmica sobre a ordem topológica: o número de caminhos até cada vértice
+    /// é a soma dos caminhos até os seus predecessores. Arestas paralelas geram caminhos
+    /// distintos, e `from == to` conta o caminho vazio (resultado 1). A soma é saturante,
+    /// então contagens enormes param em `u64::MAX`. Vértices inexistentes resultam em 0.
+    ///
+    /// `CycleError` se o grafo tiver ciclo, já que a contagem seria infinita.
+    pub fn count_paths_dag(&self, from: i32, to: i32) -> Result<u64, CycleError> {
+        let order = self.topological_iter()?;
+        if !self.vertice_exists(from) || !self.vertice_exists(to) {
+            return Ok(0);
+        }
+
+        let mut paths: HashMap<i32, u64> = HashMap::from([(from, 1)]);
+        for v in order.skip_while(|&v| v != from) {
+            let Some(&count) = paths.get(&v) else {
+                continue;
+            };
+            if v == to {
+                return Ok(count);
+            }
+            for e in self.get_vertice_arc(v).unwrap().edges_vec_ref() {
+                let entry = paths.entry(e.destiny_key()).or_insert(0);
+                *entry = entry.saturating_add(count);
+            }
+        }
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// DAG aleatório: toda aresta vai de uma chave menor para uma maior
+    fn random_dag(rng: &mut StdRng, vertices: i32, edges: usize) -> DiGraph {
+        let mut g = DiGraph::new();
+        for v in 0..vertices {
+            g.add_vertice(v);
+        }
+        for _ in 0..edges {
+            let (v, w) = (rng.gen_range(0..vertices), rng.gen_range(0..vertices));
+            if v != w {
+                g.add_edge(Edge::new(v.min(w), v.max(w)));
+            }
+        }
+        g
+    }
+
+    fn assert_topological(g: &DiGraph, order: &[i32]) {
+        assert_eq!(order.len(), g.vertices_length());
+        let position: HashMap<i32, usize> =
+            order.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        for e in g.all_edges() {
+            assert!(position[&e.origin_key()] < position[&e.destiny_key()]);
+        }
+    }
+
+    #[test]
+    fn topological_iter_yields_a_valid_order() {
+        let mut rng = StdRng::seed_from_u64(381);
+        for _ in 0..200 {
+            let g = random_dag(&mut rng, 10, 20);
+            let order: Vec<i32> = g.topological_iter().unwrap().collect();
+            assert_topological(&g, &order);
+        }
+    }
+
+    #[test]
+    fn topological_iter_is_lazy_and_prefers_smaller_keys() {
+        let g = DiGraph::from_edge_list(&[(3, 1), (3, 2), (1, 0), (2, 0), (5, 4)]);
+        let mut iter = g.topological_iter().unwrap();
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 0, 5, 4]);
+    }
+
+    #[test]
+    fn topological_iter_reports_the_cycle() {
+        // 1 -> 2 -> 3 -> 1, com 0 antes do ciclo e 4 depois
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+        let err = g.topological_iter().err().unwrap();
+        assert_eq!(err.remaining, vec![1, 2, 3, 4]);
+
+        let with_loop = DiGraph::from_edge_list(&[(0, 0)]);
+        assert!(with_loop.topological_iter().is_err());
+    }
+
+    #[test]
+    fn count_paths_in_a_diamond() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        assert_eq!(g.count_paths_dag(0, 3), Ok(2));
+        assert_eq!(g.count_paths_dag(1, 3), Ok(1));
+        assert_eq!(g.count_paths_dag(3, 0), Ok(0));
+        assert_eq!(g.count_paths_dag(2, 2), Ok(1));
+        assert_eq!(g.count_paths_dag(0, 9), Ok(0));
+
+        let cyclic = DiGraph::from_edge_list(&[(0, 1), (1, 0), (1, 2)]);
+        assert!(cyclic.count_paths_dag(0, 2).is_err());
+    }
+
+    #[test]
+    fn count_paths_saturates_and_counts_parallel_edges() {
+        // 70 etapas com duas arestas paralelas cada: 2^70 caminhos
+        let mut g = DiGraph::new();
+        for v in 0..70 {
+            g.add_edge(Edge::new(v, v + 1));
+            g.add_edge(Edge::new(v, v + 1));
+        }
+        assert_eq!(g.count_paths_dag(0, 3), Ok(8));
+        assert_eq!(g.count_paths_dag(0, 70), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn count_paths_matches_enumeration() {
+        fn enumerate(g: &DiGraph, v: i32, to: i32) -> u64 {
+            if v == to {
+                return 1;
+            }
+            let edges = g.get_vertice_arc(v).unwrap().edges_sorted();
+            edges
+                .iter()
+                .map(|e| enumerate(g, e.destiny_key(), to))
+                .sum()
+        }
+        let mut rng = StdRng::seed_from_u64(390);
+        for _ in 0..200 {
+            let g = random_dag(&mut rng, 8, 16);
+            let (from, to) = (rng.gen_range(0..8), rng.gen_range(0..8));
+            assert_eq!(g.count_paths_dag(from, to), Ok(enumerate(&g, from, to)));
+        }
+    }
+
+    #[test]
+    fn is_dag_detects_back_edges() {
+        let dag = DiGraph::from_edge_list(&[(0, 1), (0, 2), (1, 3), (2, 3), (5, 4)]);
+        assert!(dag.is_dag());
+        assert!(DiGraph::new().is_dag());
+
+        // Ciclo 1 -> 2 -> 3 -> 1 longe da primeira raiz
+        let cyclic = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 3), (3, 1)]);
+        assert!(!cyclic.is_dag());
+        assert!(!DiGraph::from_edge_list(&[(0, 0)]).is_dag());
+    }
+
+    #[test]
+    fn is_dag_agrees_with_topological_iter() {
+        let mut rng = StdRng::seed_from_u64(397);
+        for _ in 0..200 {
+            let mut g = random_dag(&mut rng, 8, 12);
+            assert!(g.is_dag());
+            if rng.gen_bool(0.5) {
+                g.add_edge(Edge::new(rng.gen_range(0..8), rng.gen_range(0..8)));
+            }
+            assert_eq!(g.is_dag(), g.topological_iter().is_ok());
+        }
+    }
+
+    /// Pares `(u, v)` com `v` alcançável a partir de `u` por um caminho não vazio
+    fn reachable_pairs(g: &DiGraph) -> HashSet<(i32, i32)> {
+        let mut pairs = HashSet::new();
+        for u in g.vertices_sorted() {
+            let mut stack = g.get_sucessor(u).unwrap_or_default();
+            while let Some(v) = stack.pop() {
+                if pairs.insert((u, v)) {
+                    stack.extend(g.get_sucessor(v).unwrap_or_default());
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn transitive_reduction_drops_shortcut_edges() {
+        let mut g = DiGraph::from_weighted_edges(&[(0, 1, 4), (1, 2, 6), (0, 2, 1), (3, 3, 0)]);
+        assert!(g.transitive_reduction().is_err());
+
+        g.remove_vertice(3);
+        g.add_vertice(3);
+        g.add_edge(Edge::new(1, 2));
+        let reduced = g.transitive_reduction().unwrap();
+        // 0 -> 2 sai por causa de 0 -> 1 -> 2; da paralela 1 -> 2 fica a de menor id
+        let edges: Vec<_> = reduced
+            .edges_sorted()
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+        assert_eq!(edges, vec![(0, 1, 4), (1, 2, 6)]);
+        assert_eq!(reduced.vertices_sorted(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn transitive_reduction_is_minimal_and_keeps_reachability() {
+        let mut rng = StdRng::seed_from_u64(399);
+        for _ in 0..100 {
+            let g = random_dag(&mut rng, 8, 16);
+            let reduced = g.transitive_reduction().unwrap();
+            assert_eq!(reachable_pairs(&reduced), reachable_pairs(&g));
+
+            // Tirar qualquer aresta da redução muda a alcançabilidade
+            for e in reduced.all_edges() {
+                let mut smaller = reduced.clone();
+                smaller.remove_edge(e);
+                assert_ne!(reachable_pairs(&smaller), reachable_pairs(&g));
+            }
+        }
+    }
+
+    #[test]
+    fn descendant_count_counts_shared_descendants_once() {
+        // Losango: 3 e 4 são alcançados por dois caminhos a partir de 0
+        let g = DiGraph::from_edge_list(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 5)]);
+        assert!(g.descendant_count().is_err());
+
+        let g = DiGraph::from_edge_list(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+        let counts = g.descendant_count().unwrap();
+        assert_eq!(
+            counts,
+            HashMap::from([(0, 4), (1, 2), (2, 2), (3, 1), (4, 0)])
+        );
+
+        // Mais de 64 vértices ocupam várias palavras
+        let chain: Vec<(i32, i32)> = (0..99).map(|v| (v, v + 1)).collect();
+        let counts = DiGraph::from_edge_list(&chain).descendant_count().unwrap();
+        assert_eq!(counts[&0], 99);
+        assert_eq!(counts[&40], 59);
+    }
+
+    #[test]
+    fn descendant_count_matches_reachable_pairs() {
+        let mut rng = StdRng::seed_from_u64(401);
+        for _ in 0..100 {
+            let g = random_dag(&mut rng, 10, 20);
+            let pairs = reachable_pairs(&g);
+            for (v, count) in g.descendant_count().unwrap() {
+                let expected = pairs.iter().filter(|&&(u, _)| u == v).count();
+                assert_eq!(count, expected as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn layer_assignment_uses_longest_path_from_sources() {
+        // 3 depende de 0 diretamente e de 0 -> 1 -> 2, então fica na camada 3
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (2, 3), (0, 3), (4, 3), (5, 6)]);
+        let layers = g.layer_assignment().unwrap();
+        assert_eq!(
+            layers,
+            HashMap::from([(0, 0), (1, 1), (2, 2), (3, 3), (4, 0), (5, 0), (6, 1)])
+        );
+        assert!(DiGraph::from_edge_list(&[(0, 1), (1, 0)])
+            .layer_assignment()
+            .is_err());
+    }
+
+    #[test]
+    fn layer_assignment_puts_every_vertex_below_its_predecessors() {
+        let mut rng = StdRng::seed_from_u64(405);
+        for _ in 0..100 {
+            let g = random_dag(&mut rng, 10, 20);
+            let layers = g.layer_assignment().unwrap();
+            for v in g.vertices_sorted() {
+                let predecessors = g.predecessor(v).unwrap_or_default();
+                let expected = predecessors
+                    .iter()
+                    .map(|u| layers[u] + 1)
+                    .max()
+                    .unwrap_or(0);
+                assert_eq!(layers[&v], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn reachability_bitsets_of_a_small_dag() {
+        let g = DiGraph::from_edge_list(&[(1, 2), (1, 3), (2, 4), (3, 4), (5, 4)]);
+        let bits = g.reachability_bitsets();
+        // Índices seguem vertices_sorted: [1, 2, 3, 4, 5]
+        assert_eq!(bits[&1], vec![false, true, true, true, false]);
+        assert_eq!(bits[&5], vec![false, false, false, true, false]);
+        assert_eq!(bits[&4], vec![false; 5]);
+
+        // Com ciclo, o próprio vértice aparece
+        let cyclic = DiGraph::from_edge_list(&[(1, 2), (2, 1), (2, 3)]);
+        let bits = cyclic.reachability_bitsets();
+        assert_eq!(bits[&1], vec![true, true, true]);
+        assert_eq!(bits[&3], vec![false, false, false]);
+    }
+
+    #[test]
+    fn reachability_bitsets_match_reachable_pairs() {
+        let mut rng = StdRng::seed_from_u64(431);
+        for round in 0..100 {
+            // Metade acíclicos, metade com arestas quaisquer; 70 vértices usam duas palavras
+            let mut g = random_dag(&mut rng, 70, 140);
+            if round % 2 == 1 {
+                for _ in 0..10 {
+                    g.add_edge(Edge::new(rng.gen_range(0..70), rng.gen_range(0..70)));
+                }
+            }
+            let pairs = reachable_pairs(&g);
+            let keys = g.vertices_sorted();
+            for (v, bits) in g.reachability_bitsets() {
+                for (j, &reached) in bits.iter().enumerate() {
+                    assert_eq!(reached, pairs.contains(&(v, keys[j])));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn would_create_cycle_on_a_chain() {
+        let g = DiGraph::from_edge_list(&[(0, 1), (1, 2), (3, 2)]);
+        // 0 alcança 2, então 2 -> 0 fecharia um ciclo; um laço sempre fecha
+        assert!(g.would_create_cycle(2, 0));
+        assert!(g.would_create_cycle(1, 0));
+        assert!(g.would_create_cycle(1, 1));
+        assert!(!g.would_create_cycle(0, 2));
+        assert!(g.would_create_cycle(2, 3));
+        assert!(!g.would_create_cycle(1, 3));
+        assert!(!g.would_create_cycle(0, 3));
+        assert_eq!(g.edges_length(), 3);
+    }
+
+    #[test]
+    fn incremental_dag_construction_stays_acyclic() {
+        let mut rng = StdRng::seed_from_u64(440);
+        for _ in 0..50 {
+            let mut g = random_dag(&mut rng, 8, 6);
+            for _ in 0..20 {
+                let (v, w) = (rng.gen_range(0..8), rng.gen_range(0..8));
+                let pairs = reachable_pairs(&g);
+                let creates = g.would_create_cycle(v, w);
+                assert_eq!(creates, v == w || pairs.contains(&(w, v)));
+
+                // Adicionar a aresta numa cópia confirma a resposta
+                let mut copy = g.clone();
+                copy.add_edge(Edge::new(v, w));
+                assert_eq!(copy.is_dag(), !creates);
+                if !creates {
+                    g = copy;
+                }
+            }
+            assert!(g.is_dag());
+        }
+    }
+}
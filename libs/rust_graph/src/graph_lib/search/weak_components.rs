@@ -0,0 +1,195 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Encontra os componentes fracamente conexos (direção das arestas ignorada).
+    ///
+    /// Cada componente vem com as chaves em ordem crescente, e os componentes
+    /// são ordenados pelo seu menor vértice.
+    pub fn weakly_connected_components(&self) -> Vec<Vec<i32>> {
+        let mut visited: HashSet<i32> = HashSet::with_capacity(self.vertices_length());
+        let mut components = Vec::new();
+
+        for root in self.vertices_sorted() {
+            if !visited.contains(&root) {
+                components.push(self.flood(root, &mut visited));
+            }
+        }
+        components
+    }
+
+    /// Retorna o componente fracamente conexo que contém `key`, em ordem crescente.
+    ///
+    /// Faz uma única inundação a partir de `key`, sem calcular os demais componentes.
+    /// `None` se o vértice não existir.
+    pub fn component_of(&self, key: i32) -> Option<Vec<i32>> {
+        if !self.vertice_exists(key) {
+            return None;
+        }
+        Some(self.flood(key, &mut HashSet::new()))
+    }
+
+    /// Inunda (BFS sem direção) a partir de `root`, marcando os vértices em `visited`.
+    ///
+    /// Retorna os vértices alcançados, em ordem crescente.
+    fn flood(&self, root: i32, visited: &mut HashSet<i32>) -> Vec<i32> {
+        visited.insert(root);
+        let mut component = vec![root];
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            for w in self.undirected_neighbors(v).unwrap_or_default() {
+                if visited.insert(w) {
+                    component.push(w);
+                    queue.push_back(w);
+                }
+            }
+        }
+        component.sort();
+        component
+    }
+
+    /// Conta quantos componentes fracamente conexos restariam sem os vértices de `removed`.
+    ///
+    /// O grafo não é alterado: a inundação apenas pula os vértices removidos.
+    /// Chaves inexistentes em `removed` são ignoradas.
+    pub fn components_after_removing(&self, removed: &[i32]) -> usize {
+        let mut visited: HashSet<i32> = removed.iter().copied().collect();
+        let mut count = 0;
+
+        for root in self.vertices_sorted() {
+            if !visited.insert(root) {
+                continue;
+            }
+            count += 1;
+            let mut queue = VecDeque::from([root]);
+            while let Some(v) = queue.pop_front() {
+                for w in self.undirected_neighbors(v).unwrap_or_default() {
+                    if visited.insert(w) {
+                        queue.push_back(w);
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    /// Árvore geradora (BFS, direção ignorada) do componente fracamente conexo de `start`.
+    ///
+    /// Retorna as arestas da árvore, com a direção, id e peso originais; são
+    /// `tamanho do componente - 1` arestas. Os vizinhos de cada vértice são explorados em
+    /// ordem crescente (sucessores e depois predecessores). Vazio se `start` não existir.
+    ///
+    /// Versão barata, sem pesos; para a árvore mínima veja o módulo `mst`.
+    pub fn spanning_tree(&self, start: i32) -> Vec<Edge> {
+        let mut tree = Vec::new();
+        if !self.vertice_exists(start) {
+            return tree;
+        }
+        let mut visited: HashSet<i32> = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(v) = queue.pop_front() {
+            let vertice = self.get_vertice_arc(v).unwrap();
+            let mut back_edges = vertice.back_edges_hashmap();
+            back_edges.sort_by_key(|e| (e.origin_key(), e.id()));
+
+            let outgoing = vertice.edges_sorted().into_iter().map(|e| (e.destiny_key(), e));
+            let incoming = back_edges.into_iter().map(|e| (e.origin_key(), e));
+            for (w, e) in outgoing.chain(incoming) {
+                if visited.insert(w) {
+                    tree.push(e);
+                    queue.push_back(w);
+                }
+            }
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weak_components_ignore_direction() {
+        let mut g = DiGraph::from_edge_list(&[(3, 1), (2, 3), (7, 5), (5, 6)]);
+        g.add_vertice(4);
+        assert_eq!(
+            g.weakly_connected_components(),
+            vec![vec![1, 2, 3], vec![4], vec![5, 6, 7]]
+        );
+    }
+
+    #[test]
+    fn removing_an_articulation_point_splits_the_graph() {
+        // Dois triângulos ligados pelo vértice 3
+        let g = DiGraph::from_edge_list(&[(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 3)]);
+        assert_eq!(g.components_after_removing(&[]), 1);
+        assert_eq!(g.components_after_removing(&[3]), 2);
+        assert_eq!(g.components_after_removing(&[1]), 1);
+        // Chaves inexistentes são ignoradas e o grafo não muda
+        assert_eq!(g.components_after_removing(&[3, 4, 99]), 2);
+        assert_eq!(g.weakly_connected_components().len(), 1);
+    }
+
+    #[test]
+    fn component_of_returns_only_its_component() {
+        let mut g = DiGraph::from_edge_list(&[(3, 1), (2, 3), (7, 5), (5, 6)]);
+        g.add_vertice(4);
+        assert_eq!(g.component_of(1), Some(vec![1, 2, 3]));
+        assert_eq!(g.component_of(6), Some(vec![5, 6, 7]));
+        assert_eq!(g.component_of(4), Some(vec![4]));
+        assert_eq!(g.component_of(9), None);
+
+        // Cada vértice recebe exatamente o seu componente da lista completa
+        for component in g.weakly_connected_components() {
+            for &v in component.iter() {
+                assert_eq!(g.component_of(v).as_ref(), Some(&component));
+            }
+        }
+    }
+
+    #[test]
+    fn spanning_tree_is_acyclic_and_covers_the_component() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        use std::collections::HashMap;
+
+        let mut rng = StdRng::seed_from_u64(419);
+        for _ in 0..200 {
+            let edges: Vec<(i32, i32)> = (0..10)
+                .map(|_| (rng.gen_range(0..10), rng.gen_range(0..10)))
+                .collect();
+            let g = DiGraph::from_edge_list(&edges);
+            let start = edges[0].0;
+            let component = g.component_of(start).unwrap();
+            let tree = g.spanning_tree(start);
+            assert_eq!(tree.len(), component.len() - 1);
+
+            // União de conjuntos: nenhuma aresta da árvore fecha um ciclo
+            let mut parent: HashMap<i32, i32> = component.iter().map(|&v| (v, v)).collect();
+            fn find(parent: &mut HashMap<i32, i32>, v: i32) -> i32 {
+                let p = parent[&v];
+                if p == v {
+                    return v;
+                }
+                let root = find(parent, p);
+                parent.insert(v, root);
+                root
+            }
+            for e in tree.iter() {
+                let (a, b) = (
+                    find(&mut parent, e.origin_key()),
+                    find(&mut parent, e.destiny_key()),
+                );
+                assert_ne!(a, b);
+                parent.insert(a, b);
+                assert!(g
+                    .get_edges(e.origin_key(), e.destiny_key())
+                    .unwrap()
+                    .contains(e));
+            }
+        }
+        assert!(DiGraph::new().spanning_tree(0).is_empty());
+    }
+}
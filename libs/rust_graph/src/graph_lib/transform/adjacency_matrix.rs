@@ -0,0 +1,163 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{graph_lib::edge::Edge, tools::inifinity::Infinity, DiGraph};
+
+/// Erro ao montar um grafo a partir de uma matriz de adjacência
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdjacencyMatrixError {
+    /// O número de linhas é diferente do número de chaves
+    RowCount { rows: usize, keys: usize },
+    /// A linha `row` não tem uma coluna por chave
+    RowLength { row: usize, len: usize, keys: usize },
+    /// A mesma chave aparece mais de uma vez
+    DuplicateKey(i32),
+}
+
+impl fmt::Display for AdjacencyMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdjacencyMatrixError::RowCount { rows, keys } => {
+                write!(f, "a matriz tem {} linhas para {} chaves", rows, keys)
+            }
+            AdjacencyMatrixError::RowLength { row, len, keys } => {
+                write!(f, "a linha {} tem {} colunas para {} chaves", row, len, keys)
+            }
+            AdjacencyMatrixError::DuplicateKey(key) => write!(f, "chave {} repetida", key),
+        }
+    }
+}
+
+impl std::error::Error for AdjacencyMatrixError {}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Matriz de adjacência com pesos.
+    ///
+    /// Retorna a matriz e as chaves que indexam suas linhas e colunas (em ordem crescente).
+    /// `matrix[i][j]` é o peso da aresta `keys[i] -> keys[j]` (o menor peso, se houver arestas
+    /// paralelas) ou `Infinite` se não houver aresta. A diagonal vale 0, como em uma matriz
+    /// de distâncias, então laços não aparecem.
+    pub fn to_adjacency_matrix(&self) -> (Vec<Vec<Infinity>>, Vec<i32>) {
+        let keys = self.vertices_sorted();
+        let matrix = keys
+            .iter()
+            .map(|&v| {
+                keys.iter()
+                    .map(|&w| {
+                        if v == w {
+                            return Infinity::new(0);
+                        }
+                        self.get_edges(v, w)
+                            .and_then(|edges| edges.iter().map(|e| e.weight()).min())
+                            .map_or(Infinity::Infinite, Infinity::new)
+                    })
+                    .collect()
+            })
+            .collect();
+        (matrix, keys)
+    }
+
+    /// Cria um grafo a partir de uma matriz de pesos e das chaves de suas linhas/colunas.
+    ///
+    /// Toda entrada finita fora da diagonal vira uma aresta `keys[i] -> keys[j]`; a diagonal é
+    /// ignorada. Todas as chaves viram vértices, mesmo sem arestas.
+    ///
+    /// `AdjacencyMatrixError` se as dimensões não baterem com `keys` ou se houver chave repetida.
+    pub fn from_adjacency_matrix(
+        matrix: &[Vec<Infinity>],
+        keys: &[i32],
+    ) -> Result<DiGraph, AdjacencyMatrixError> {
+        if matrix.len() != keys.len() {
+            return Err(AdjacencyMatrixError::RowCount {
+                rows: matrix.len(),
+                keys: keys.len(),
+            });
+        }
+        if let Some((row, r)) = matrix.iter().enumerate().find(|(_, r)| r.len() != keys.len()) {
+            return Err(AdjacencyMatrixError::RowLength {
+                row,
+                len: r.len(),
+                keys: keys.len(),
+            });
+        }
+        let mut seen = HashSet::with_capacity(keys.len());
+        if let Some(&key) = keys.iter().find(|&&k| !seen.insert(k)) {
+            return Err(AdjacencyMatrixError::DuplicateKey(key));
+        }
+
+        let mut graph = DiGraph::new_sized(keys.len() as u32);
+        for &key in keys {
+            graph.add_vertice(key);
+        }
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, weight) in row.iter().enumerate() {
+                if i != j && !weight.is_infinite() {
+                    graph.add_edge(Edge::new_weighted(keys[i], keys[j], weight.unwrap()));
+                }
+            }
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn matrix_graph_matrix_round_trip() {
+        let mut rng = StdRng::seed_from_u64(403);
+        for _ in 0..100 {
+            let n = rng.gen_range(0..7);
+            let keys: Vec<i32> = (0..n).map(|i| i * 3 - 4).collect();
+            let matrix: Vec<Vec<Infinity>> = (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| match (i == j, rng.gen_bool(0.4)) {
+                            (true, _) => Infinity::new(0),
+                            (false, true) => Infinity::new(rng.gen_range(-5..20)),
+                            (false, false) => Infinity::Infinite,
+                        })
+                        .collect()
+                })
+                .collect();
+
+            let g = DiGraph::from_adjacency_matrix(&matrix, &keys).unwrap();
+            assert_eq!(g.to_adjacency_matrix(), (matrix, keys));
+        }
+    }
+
+    #[test]
+    fn from_adjacency_matrix_ignores_diagonal() {
+        let matrix = vec![
+            vec![Infinity::new(4), Infinity::new(2)],
+            vec![Infinity::Infinite, Infinity::Infinite],
+        ];
+        let g = DiGraph::from_adjacency_matrix(&matrix, &[10, 20]).unwrap();
+        assert_eq!(g.edges_length(), 1);
+        assert_eq!(g.edge_weights_between(10, 20), vec![2]);
+        assert!(g.vertice_exists(20));
+    }
+
+    #[test]
+    fn from_adjacency_matrix_rejects_bad_dimensions() {
+        let row = vec![Infinity::new(0), Infinity::Infinite];
+        assert_eq!(
+            DiGraph::from_adjacency_matrix(std::slice::from_ref(&row), &[1, 2]).err(),
+            Some(AdjacencyMatrixError::RowCount { rows: 1, keys: 2 })
+        );
+        assert_eq!(
+            DiGraph::from_adjacency_matrix(&[row.clone(), vec![Infinity::new(0)]], &[1, 2]).err(),
+            Some(AdjacencyMatrixError::RowLength {
+                row: 1,
+                len: 1,
+                keys: 2
+            })
+        );
+        assert_eq!(
+            DiGraph::from_adjacency_matrix(&[row.clone(), row], &[1, 1]).err(),
+            Some(AdjacencyMatrixError::DuplicateKey(1))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+/// Erro ao decodificar um grafo de `DiGraph::from_bytes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Os dados acabaram no byte `offset`, no meio de um campo
+    UnexpectedEnd { offset: usize },
+    /// Sobraram bytes depois da última aresta, a partir de `offset`
+    TrailingBytes { offset: usize },
+    /// A lista de vértices repete a chave
+    DuplicateVertex(i32),
+    /// Uma aresta cita uma chave que não está na lista de vértices
+    UnknownVertex(i32),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd { offset } => {
+                write!(f, "dados terminam no byte {} no meio de um campo", offset)
+            }
+            DecodeError::TrailingBytes { offset } => {
+                write!(f, "bytes sobrando a partir do byte {}", offset)
+            }
+            DecodeError::DuplicateVertex(key) => write!(f, "vértice {} repetido", key),
+            DecodeError::UnknownVertex(key) => write!(f, "aresta cita o vértice {} inexistente", key),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Leitor sequencial de campos little-endian
+struct ByteReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl ByteReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + N)
+            .ok_or(DecodeError::UnexpectedEnd { offset: self.data.len() })?;
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    fn read_i32(&mut self) -> Result<i32, DecodeError> {
+        Ok(i32::from_le_bytes(self.take()?))
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Serializa o grafo em um formato binário compacto.
+    ///
+    /// Formato (tudo little-endian):
+    /// - `u64` quantidade de vértices, seguida das chaves (`i32` cada, em ordem crescente)
+    /// - `u64` quantidade de arestas, seguida de `origem, destino, peso` (`i32` cada),
+    ///   na ordem de `edges_sorted`
+    ///
+    /// Vértices isolados e arestas paralelas são preservados; ids e atributos de arestas não.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let keys = self.vertices_sorted();
+        let edges = self.edges_sorted();
+        let mut bytes = Vec::with_capacity(16 + keys.len() * 4 + edges.len() * 12);
+
+        bytes.extend_from_slice(&(keys.len() as u64).to_le_bytes());
+        for key in keys {
+            bytes.extend_from_slice(&key.to_le_bytes());
+        }
+        bytes.extend_from_slice(&(edges.len() as u64).to_le_bytes());
+        for e in edges {
+            bytes.extend_from_slice(&e.origin_key().to_le_bytes());
+            bytes.extend_from_slice(&e.destiny_key().to_le_bytes());
+            bytes.extend_from_slice(&e.weight().to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Lê um grafo gerado por `to_bytes`. As arestas recebem ids novos.
+    ///
+    /// `DecodeError` se os dados estiverem truncados, sobrarem bytes ou se as arestas
+    /// citarem vértices que não estão na lista.
+    pub fn from_bytes(data: &[u8]) -> Result<DiGraph, DecodeError> {
+        let mut reader = ByteReader { data, offset: 0 };
+
+        let vertices_len = reader.read_u64()?;
+        let mut keys = HashSet::new();
+        let mut graph = DiGraph::new();
+        for _ in 0..vertices_len {
+            let key = reader.read_i32()?;
+            if !keys.insert(key) {
+                return Err(DecodeError::DuplicateVertex(key));
+            }
+            graph.add_vertice(key);
+        }
+
+        // As arestas entram de uma vez no final, sem a verificação de debug a cada inserção
+        let edges_len = reader.read_u64()?;
+        let mut edges = Vec::new();
+        for _ in 0..edges_len {
+            let (v, w, weight) = (reader.read_i32()?, reader.read_i32()?, reader.read_i32()?);
+            if let Some(&key) = [v, w].iter().find(|k| !keys.contains(k)) {
+                return Err(DecodeError::UnknownVertex(key));
+            }
+            edges.push(Edge::new_weighted(v, w, weight));
+        }
+        graph.add_edges_bulk(edges);
+
+        if reader.offset != data.len() {
+            return Err(DecodeError::TrailingBytes { offset: reader.offset });
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn triples(g: &DiGraph) -> Vec<(i32, i32, i32)> {
+        g.edges_sorted()
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+            .collect()
+    }
+
+    #[test]
+    fn bytes_round_trip_on_random_graphs() {
+        let mut rng = StdRng::seed_from_u64(427);
+        for _ in 0..100 {
+            let mut g = DiGraph::new();
+            for _ in 0..rng.gen_range(0..20) {
+                let (v, w) = (rng.gen_range(-50..50), rng.gen_range(-50..50));
+                g.add_edge(Edge::new_weighted(v, w, rng.gen_range(i32::MIN..i32::MAX)));
+            }
+            for _ in 0..3 {
+                g.add_vertice(rng.gen_range(100..200));
+            }
+
+            let bytes = g.to_bytes();
+            assert_eq!(
+                bytes.len(),
+                16 + g.vertices_length() * 4 + g.edges_length() * 12
+            );
+            let decoded = DiGraph::from_bytes(&bytes).unwrap();
+            assert_eq!(decoded.vertices_sorted(), g.vertices_sorted());
+            assert_eq!(triples(&decoded), triples(&g));
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+    }
+
+    #[test]
+    fn from_bytes_rejects_malformed_data() {
+        let g = DiGraph::from_weighted_edges(&[(1, 2, 7)]);
+        let bytes = g.to_bytes();
+        assert_eq!(
+            DiGraph::from_bytes(&bytes[..bytes.len() - 1]).err(),
+            Some(DecodeError::UnexpectedEnd {
+                offset: bytes.len() - 1
+            })
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            DiGraph::from_bytes(&trailing).err(),
+            Some(DecodeError::TrailingBytes {
+                offset: bytes.len()
+            })
+        );
+
+        // Dois vértices com a mesma chave, sem arestas
+        let mut duplicate = 2u64.to_le_bytes().to_vec();
+        duplicate.extend_from_slice(&5i32.to_le_bytes());
+        duplicate.extend_from_slice(&5i32.to_le_bytes());
+        duplicate.extend_from_slice(&0u64.to_le_bytes());
+        assert_eq!(
+            DiGraph::from_bytes(&duplicate).err(),
+            Some(DecodeError::DuplicateVertex(5))
+        );
+
+        // Aresta 5 -> 6 com só o vértice 5 declarado
+        let mut unknown = 1u64.to_le_bytes().to_vec();
+        unknown.extend_from_slice(&5i32.to_le_bytes());
+        unknown.extend_from_slice(&1u64.to_le_bytes());
+        for field in [5i32, 6, 1] {
+            unknown.extend_from_slice(&field.to_le_bytes());
+        }
+        assert_eq!(
+            DiGraph::from_bytes(&unknown).err(),
+            Some(DecodeError::UnknownVertex(6))
+        );
+        assert_eq!(
+            DiGraph::from_bytes(&[]).err(),
+            Some(DecodeError::UnexpectedEnd { offset: 0 })
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+
+use crate::DiGraph;
+
+/// Erro ao aplicar uma sequência de contrações
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContractionError {
+    /// Na contração de índice `step`, o vértice `key` não existe (talvez já tenha sido fundido)
+    MissingVertex { step: usize, key: i32 },
+    /// A contração de índice `step` funde o vértice `key` com ele mesmo
+    SameVertex { step: usize, key: i32 },
+}
+
+impl fmt::Display for ContractionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContractionError::MissingVertex { step, key } => {
+                write!(f, "contração {}: vértice {} não existe", step, key)
+            }
+            ContractionError::SameVertex { step, key } => {
+                write!(f, "contração {}: vértice {} fundido com ele mesmo", step, key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContractionError {}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Aplica uma sequência de contrações, em ordem.
+    ///
+    /// Cada par `(keep, merge_in)` funde `merge_in` em `keep` como em `merge_vertices`:
+    /// as arestas são religadas a `keep` (mantendo id e peso) e as que ligavam os dois
+    /// vértices são descartadas. Útil para reproduzir um dendrograma ou uma sequência de
+    /// fusões de comunidades.
+    ///
+    /// Para na primeira contração inválida e retorna `ContractionError`; as contrações
+    /// anteriores continuam aplicadas.
+    pub fn contract_sequence(&mut self, merges: &[(i32, i32)]) -> Result<(), ContractionError> {
+        for (step, &(keep, merge_in)) in merges.iter().enumerate() {
+            if keep == merge_in {
+                return Err(ContractionError::SameVertex { step, key: keep });
+            }
+            if let Some(&key) = [keep, merge_in].iter().find(|&&k| !self.vertice_exists(k)) {
+                return Err(ContractionError::MissingVertex { step, key });
+            }
+            self.merge_vertices(keep, merge_in);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_sequential_contractions() {
+        let mut g = DiGraph::from_edge_list(&[(1, 2), (2, 3), (3, 4), (4, 1), (2, 5), (3, 5)]);
+        g.contract_sequence(&[(2, 3), (2, 4)]).unwrap();
+        g.debug_assert_consistent();
+
+        // 3 -> 5 virou uma paralela de 2 -> 5; 2 -> 3 e 3 -> 4 sumiram
+        assert_eq!(g.vertices_sorted(), vec![1, 2, 5]);
+        let edges: Vec<(i32, i32)> = g.edges_sorted().iter().map(|e| e.v_w()).collect();
+        assert_eq!(edges, vec![(1, 2), (2, 1), (2, 5), (2, 5)]);
+        assert_eq!(g.edges_length(), 4);
+    }
+
+    #[test]
+    fn contract_sequence_stops_at_a_merged_vertex() {
+        let mut g = DiGraph::from_edge_list(&[(1, 2), (2, 3), (3, 1)]);
+        assert_eq!(
+            g.contract_sequence(&[(1, 3), (2, 3), (1, 2)]),
+            Err(ContractionError::MissingVertex { step: 1, key: 3 })
+        );
+        // A primeira fusão continua aplicada
+        assert_eq!(g.vertices_sorted(), vec![1, 2]);
+        assert_eq!(
+            g.contract_sequence(&[(2, 2)]),
+            Err(ContractionError::SameVertex { step: 0, key: 2 })
+        );
+        assert_eq!(g.contract_sequence(&[]), Ok(()));
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use crate::DiGraph;
+
+#[allow(unused)]
+impl DiGraph {
+    /// Representação CSR (compressed sparse row) do grafo.
+    ///
+    /// Retorna `(offsets, colunas, pesos, chaves)`:
+    /// - `chaves[i]` é a chave do vértice de índice `i` (em ordem crescente)
+    /// - as arestas que saem de `chaves[i]` ocupam as posições `offsets[i]..offsets[i + 1]`
+    /// - `colunas[j]` é o índice do destino da aresta `j` e `pesos[j]` o seu peso
+    ///
+    /// `offsets` tem `vertices_length() + 1` posições. Dentro de cada linha as arestas seguem
+    /// a ordem de `Vertice::edges_sorted` (destino, depois id); arestas paralelas aparecem
+    /// uma vez cada.
+    pub fn to_csr(&self) -> (Vec<usize>, Vec<i32>, Vec<i64>, Vec<i32>) {
+        let keys = self.vertices_sorted();
+        let index: HashMap<i32, i32> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, &k)| (k, i as i32))
+            .collect();
+
+        let mut offsets = Vec::with_capacity(keys.len() + 1);
+        let mut columns = Vec::with_capacity(self.edges_length());
+        let mut weights = Vec::with_capacity(self.edges_length());
+        offsets.push(0);
+        for &v in keys.iter() {
+            for e in self.get_vertice_arc(v).unwrap().edges_sorted() {
+                columns.push(index[&e.destiny_key()]);
+                weights.push(e.weight() as i64);
+            }
+            offsets.push(columns.len());
+        }
+        (offsets, columns, weights, keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn csr_of_a_small_graph() {
+        let mut g =
+            DiGraph::from_weighted_edges(&[(10, 30, 4), (10, 20, 2), (30, 10, 7), (10, 20, 5)]);
+        g.add_vertice(40);
+        let (offsets, columns, weights, keys) = g.to_csr();
+        assert_eq!(keys, vec![10, 20, 30, 40]);
+        assert_eq!(offsets, vec![0, 3, 3, 4, 4]);
+        assert_eq!(columns, vec![1, 1, 2, 0]);
+        assert_eq!(weights, vec![2, 5, 4, 7]);
+    }
+
+    #[test]
+    fn csr_rows_match_the_adjacency() {
+        let mut rng = StdRng::seed_from_u64(411);
+        for _ in 0..100 {
+            let edges: Vec<(i32, i32, i32)> = (0..15)
+                .map(|_| {
+                    (
+                        rng.gen_range(0..8),
+                        rng.gen_range(0..8),
+                        rng.gen_range(-5..10),
+                    )
+                })
+                .collect();
+            let g = DiGraph::from_weighted_edges(&edges);
+            let (offsets, columns, weights, keys) = g.to_csr();
+            assert_eq!(offsets.len(), keys.len() + 1);
+            assert_eq!(*offsets.last().unwrap(), g.edges_length());
+
+            let mut rebuilt: Vec<(i32, i32, i32)> = Vec::new();
+            for (i, &v) in keys.iter().enumerate() {
+                for j in offsets[i]..offsets[i + 1] {
+                    rebuilt.push((v, keys[columns[j] as usize], weights[j] as i32));
+                }
+            }
+            let mut expected = edges.clone();
+            expected.sort();
+            rebuilt.sort();
+            assert_eq!(rebuilt, expected);
+        }
+    }
+}
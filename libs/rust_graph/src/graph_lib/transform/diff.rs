@@ -0,0 +1,177 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::DiGraph;
+
+/// Diferença entre duas versões de um grafo (ver `DiGraph::diff`)
+///
+/// Arestas são triplas `(origem, destino, peso)`; todos os vetores vêm em ordem crescente.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GraphDiff {
+    /// Vértices que só existem no grafo novo
+    pub added_vertices: Vec<i32>,
+    /// Vértices que só existem no grafo antigo
+    pub removed_vertices: Vec<i32>,
+    /// Arestas que só existem no grafo novo (repetidas se a multiplicidade aumentou)
+    pub added_edges: Vec<(i32, i32, i32)>,
+    /// Arestas que só existem no grafo antigo (repetidas se a multiplicidade diminuiu)
+    pub removed_edges: Vec<(i32, i32, i32)>,
+}
+
+#[allow(unused)]
+impl GraphDiff {
+    /// `true` se os grafos têm os mesmos vértices e as mesmas arestas.
+    pub fn is_empty(&self) -> bool {
+        self.added_vertices.is_empty()
+            && self.removed_vertices.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
+#[allow(unused)]
+impl DiGraph {
+    /// O que mudou de `self` (versão antiga) para `other` (versão nova).
+    ///
+    /// Arestas são comparadas por `(origem, destino, peso)`, não por id, então uma cópia
+    /// reconstruída não gera diferenças. As arestas são tratadas como multiconjunto: se
+    /// `1 -> 2` com peso 5 aparece duas vezes em `self` e três em `other`, uma cópia entra em
+    /// `added_edges`. Mudar o peso de uma aresta aparece como uma remoção e uma adição.
+    pub fn diff(&self, other: &DiGraph) -> GraphDiff {
+        let old_vertices: HashSet<i32> = self.get_vertice_key_array().into_iter().collect();
+        let new_vertices: HashSet<i32> = other.get_vertice_key_array().into_iter().collect();
+
+        // Multiplicidade de cada aresta em `other` menos a multiplicidade em `self`
+        let mut balance: HashMap<(i32, i32, i32), i64> = HashMap::new();
+        for e in other.all_edges() {
+            *balance
+                .entry((e.origin_key(), e.destiny_key(), e.weight()))
+                .or_insert(0) += 1;
+        }
+        for e in self.all_edges() {
+            *balance
+                .entry((e.origin_key(), e.destiny_key(), e.weight()))
+                .or_insert(0) -= 1;
+        }
+
+        let mut diff = GraphDiff {
+            added_vertices: new_vertices.difference(&old_vertices).copied().collect(),
+            removed_vertices: old_vertices.difference(&new_vertices).copied().collect(),
+            ..GraphDiff::default()
+        };
+        for (edge, count) in balance {
+            let target = if count > 0 {
+                &mut diff.added_edges
+            } else {
+                &mut diff.removed_edges
+            };
+            target.extend(std::iter::repeat_n(edge, count.unsigned_abs() as usize));
+        }
+        diff.added_vertices.sort();
+        diff.removed_vertices.sort();
+        diff.added_edges.sort();
+        diff.removed_edges.sort();
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_lib::edge::Edge;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Arestas como triplas ordenadas
+    fn triples(g: &DiGraph) -> Vec<(i32, i32, i32)> {
+        let mut edges: Vec<_> = g
+            .all_edges()
+            .iter()
+            .map(|e| (e.origin_key(), e.destiny_key(), e.weight()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn diff_against_a_modified_copy() {
+        let old = DiGraph::from_weighted_edges(&[(1, 2, 5), (1, 2, 5), (2, 3, 1), (3, 4, 2)]);
+        let mut new = old.clone();
+        assert!(old.diff(&new).is_empty());
+
+        new.remove_vertice(4);
+        new.add_edge(Edge::new_weighted(1, 2, 5));
+        new.add_edge(Edge::new_weighted(5, 1, 0));
+        new.add_vertice(6);
+        new.remove_edge(Edge::new_weighted(2, 3, 1));
+        new.add_edge(Edge::new_weighted(2, 3, 7));
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff,
+            GraphDiff {
+                added_vertices: vec![5, 6],
+                removed_vertices: vec![4],
+                added_edges: vec![(1, 2, 5), (2, 3, 7), (5, 1, 0)],
+                removed_edges: vec![(2, 3, 1), (3, 4, 2)],
+            }
+        );
+        // No sentido contrário, adições e remoções trocam de lugar
+        let back = new.diff(&old);
+        assert_eq!(back.added_vertices, diff.removed_vertices);
+        assert_eq!(back.removed_edges, diff.added_edges);
+
+        // Uma cópia reconstruída (ids diferentes) não tem diferenças
+        let rebuilt = DiGraph::from_weighted_edges(&[(3, 4, 2), (2, 3, 1), (1, 2, 5), (1, 2, 5)]);
+        assert!(old.diff(&rebuilt).is_empty());
+    }
+
+    #[test]
+    fn applying_the_diff_reproduces_the_new_graph() {
+        let mut rng = StdRng::seed_from_u64(447);
+        for _ in 0..100 {
+            let edges: Vec<(i32, i32, i32)> = (0..12)
+                .map(|_| {
+                    (
+                        rng.gen_range(0..6),
+                        rng.gen_range(0..6),
+                        rng.gen_range(0..3),
+                    )
+                })
+                .collect();
+            let old = DiGraph::from_weighted_edges(&edges);
+            let mut new = old.clone();
+            for _ in 0..4 {
+                match rng.gen_range(0..3) {
+                    0 => new.add_edge(Edge::new_weighted(
+                        rng.gen_range(0..8),
+                        rng.gen_range(0..8),
+                        rng.gen_range(0..3),
+                    )),
+                    1 => {
+                        new.remove_vertice(rng.gen_range(0..8));
+                    }
+                    _ => {
+                        if let Some(e) = new.all_edges().first() {
+                            new.retain_edges(|other| other.id() != e.id());
+                        }
+                    }
+                }
+            }
+
+            let diff = old.diff(&new);
+            let mut vertices = old.vertices_sorted();
+            vertices.retain(|v| !diff.removed_vertices.contains(v));
+            vertices.extend(diff.added_vertices.iter());
+            vertices.sort();
+            assert_eq!(vertices, new.vertices_sorted());
+
+            let mut edges = triples(&old);
+            for removed in diff.removed_edges.iter() {
+                let at = edges.iter().position(|e| e == removed).unwrap();
+                edges.remove(at);
+            }
+            edges.extend(diff.added_edges.iter());
+            edges.sort();
+            assert_eq!(edges, triples(&new));
+        }
+    }
+}
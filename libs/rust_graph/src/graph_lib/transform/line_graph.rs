@@ -0,0 +1,66 @@
+use crate::{graph_lib::edge::Edge, DiGraph};
+
+#[allow(unused)]
+impl DiGraph {
+    /// Cria o grafo de linha L(G).
+    ///
+    /// Cada aresta de G vira um vértice de L(G) cuja chave é o id da aresta (`Edge::id`).
+    ///
+    /// Regra de adjacência (direcionada): a aresta `a -> b` liga-se à aresta `b -> c`,
+    /// ou seja, existe `e1 -> e2` em L(G) quando `e1` termina onde `e2` começa.
+    /// Um laço `a -> a` liga-se a si mesmo.
+    pub fn line_graph(&self) -> DiGraph {
+        let edges = self.edges_sorted();
+        let mut line = DiGraph::new_sized(edges.len() as u32);
+
+        for e in edges.iter() {
+            line.add_vertice(e.id() as i32);
+        }
+        for e in edges.iter() {
+            let Some(next_vertice) = self.get_vertice_arc(e.destiny_key()) else {
+                continue;
+            };
+            for next in next_vertice.edges_sorted() {
+                line.add_edge(Edge::new(e.id() as i32, next.id() as i32));
+            }
+        }
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arcs(g: &DiGraph) -> Vec<(i32, i32)> {
+        let mut arcs: Vec<_> = g.edges_sorted().iter().map(|e| e.v_w()).collect();
+        arcs.sort();
+        arcs
+    }
+
+    #[test]
+    fn line_graph_of_a_path_is_a_path() {
+        let g = DiGraph::from_edge_list(&[(1, 2), (2, 3), (3, 4)]);
+        let ids: Vec<i32> = g.edges_sorted().iter().map(|e| e.id() as i32).collect();
+        let line = g.line_graph();
+
+        let mut expected_vertices = ids.clone();
+        expected_vertices.sort();
+        assert_eq!(line.vertices_sorted(), expected_vertices);
+        let mut expected = vec![(ids[0], ids[1]), (ids[1], ids[2])];
+        expected.sort();
+        assert_eq!(arcs(&line), expected);
+    }
+
+    #[test]
+    fn line_graph_follows_direction_and_loops() {
+        // a: 1 -> 2, b: 3 -> 2, c: 2 -> 2 (laço), d: 2 -> 4
+        let g = DiGraph::from_edge_list(&[(1, 2), (3, 2), (2, 2), (2, 4)]);
+        let id = |v: i32, w: i32| g.get_edges(v, w).unwrap()[0].id() as i32;
+        let (a, b, c, d) = (id(1, 2), id(3, 2), id(2, 2), id(2, 4));
+
+        let mut expected = vec![(a, c), (a, d), (b, c), (b, d), (c, c), (c, d)];
+        expected.sort();
+        assert_eq!(arcs(&g.line_graph()), expected);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod line_graph;
+pub mod adjacency_matrix;
+pub mod csr;
+pub mod orientation;
+pub mod contraction;
+pub mod bytes;
+pub mod diff;
@@ -0,0 +1,140 @@
+use std::{collections::HashMap, fmt};
+
+use crate::DiGraph;
+
+/// Erro ao orientar um grafo segundo uma ordem de vértices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrientationError {
+    /// A ordem cita uma chave que não é vértice do grafo
+    UnknownKey(i32),
+    /// A mesma chave aparece mais de uma vez na ordem
+    DuplicateKey(i32),
+    /// Um vértice do grafo não aparece na ordem
+    MissingVertex(i32),
+}
+
+impl fmt::Display for OrientationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrientationError::UnknownKey(key) => write!(f, "chave {} não existe no grafo", key),
+            OrientationError::DuplicateKey(key) => write!(f, "chave {} repetida", key),
+            OrientationError::MissingVertex(key) => write!(f, "vértice {} ausente da ordem", key),
+        }
+    }
+}
+
+impl std::error::Error for OrientationError {}
+
+#[allow(unused)]
+impl DiGraph {
+    /// Orientação acíclica do grafo (direção das arestas ignorada) segundo a ordem total `order`.
+    ///
+    /// Cada aresta `{u, v}` vira `u -> v` se `u` aparece antes de `v` em `order`, então o
+    /// resultado é sempre um DAG. Arestas paralelas (nos dois sentidos) são mantidas, com id e
+    /// peso; laços não têm orientação acíclica e são descartados. Todos os vértices são mantidos.
+    ///
+    /// `order` deve conter cada vértice do grafo exatamente uma vez; caso contrário
+    /// retorna `OrientationError`.
+    pub fn acyclic_orientation(&self, order: &[i32]) -> Result<DiGraph, OrientationError> {
+        let mut rank: HashMap<i32, usize> = HashMap::with_capacity(order.len());
+        for (i, &key) in order.iter().enumerate() {
+            if !self.vertice_exists(key) {
+                return Err(OrientationError::UnknownKey(key));
+            }
+            if rank.insert(key, i).is_some() {
+                return Err(OrientationError::DuplicateKey(key));
+            }
+        }
+        if let Some(key) = self.vertices_sorted().into_iter().find(|k| !rank.contains_key(k)) {
+            return Err(OrientationError::MissingVertex(key));
+        }
+
+        let mut oriented = DiGraph::new_sized(order.len() as u32);
+        for &key in order {
+            oriented.add_vertice(key);
+        }
+        for e in self.edges_sorted() {
+            let (v, w) = e.v_w();
+            if v == w {
+                continue;
+            }
+            if rank[&v] < rank[&w] {
+                oriented.add_edge(e);
+            } else {
+                oriented.add_edge(e.rewired(w, v));
+            }
+        }
+        Ok(oriented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    /// Arestas sem direção `(menor, maior, peso)`, em ordem
+    fn undirected(g: &DiGraph) -> Vec<(i32, i32, i32)> {
+        let mut edges: Vec<_> = g
+            .all_edges()
+            .iter()
+            .filter(|e| e.origin_key() != e.destiny_key())
+            .map(|e| {
+                let (v, w) = e.v_w();
+                (v.min(w), v.max(w), e.weight())
+            })
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn orientation_is_a_dag_with_the_same_edges() {
+        let mut rng = StdRng::seed_from_u64(416);
+        for _ in 0..100 {
+            let edges: Vec<(i32, i32, i32)> = (0..14)
+                .map(|_| {
+                    (
+                        rng.gen_range(0..7),
+                        rng.gen_range(0..7),
+                        rng.gen_range(0..5),
+                    )
+                })
+                .collect();
+            let g = DiGraph::from_weighted_edges(&edges);
+            let mut order = g.vertices_sorted();
+            order.shuffle(&mut rng);
+
+            let oriented = g.acyclic_orientation(&order).unwrap();
+            assert!(oriented.is_dag());
+            assert_eq!(undirected(&oriented), undirected(&g));
+            assert_eq!(oriented.vertices_sorted(), g.vertices_sorted());
+            let rank: HashMap<i32, usize> =
+                order.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+            for e in oriented.all_edges() {
+                assert!(rank[&e.origin_key()] < rank[&e.destiny_key()]);
+            }
+        }
+    }
+
+    #[test]
+    fn orientation_rejects_bad_orders() {
+        let g = DiGraph::from_edge_list(&[(1, 2), (2, 3), (3, 1)]);
+        let oriented = g.acyclic_orientation(&[3, 1, 2]).unwrap();
+        let edges: Vec<(i32, i32)> = oriented.edges_sorted().iter().map(|e| e.v_w()).collect();
+        assert_eq!(edges, vec![(1, 2), (3, 1), (3, 2)]);
+
+        assert_eq!(
+            g.acyclic_orientation(&[1, 2, 9]).err(),
+            Some(OrientationError::UnknownKey(9))
+        );
+        assert_eq!(
+            g.acyclic_orientation(&[1, 2, 1]).err(),
+            Some(OrientationError::DuplicateKey(1))
+        );
+        assert_eq!(
+            g.acyclic_orientation(&[1, 3]).err(),
+            Some(OrientationError::MissingVertex(2))
+        );
+    }
+}
@@ -5,13 +5,25 @@ use std::collections::HashMap;
 /// Estrutura destinada a representar vértices em um grafo.
 ///
 /// Contém campos como `key` e `edges`.
-#[derive(Debug, PartialEq, Eq,Clone)]
+#[derive(Debug, Clone)]
 pub struct Vertice {
     key: i32,
     edges: HashMap<(i32, i32), Vec<Edge>>, // Arestas armazenadas como HashMap com vetores para permitir arestas paralelas
     back_edges: HashMap<(i32, i32), Vec<Edge>>,
+    /// Arestas sucessoras em ordem (destino, id), guardadas por `sort_edges` e descartadas
+    /// por qualquer alteração nas arestas sucessoras
+    sorted_edges: Option<Vec<Edge>>,
 }
 
+// O cache de `sorted_edges` não faz parte do conteúdo do vértice
+impl PartialEq for Vertice {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.edges == other.edges && self.back_edges == other.back_edges
+    }
+}
+
+impl Eq for Vertice {}
+
 impl Vertice {
     /// Cria um novo vértice com uma chave específica.
     ///
@@ -29,6 +41,7 @@ impl Vertice {
             key: vertice_key,
             edges: HashMap::new(),
             back_edges: HashMap::new(),
+            sorted_edges: None,
         }
     }
 
@@ -62,6 +75,11 @@ impl Vertice {
         self.edges.get(&(self.key, destiny_key))
     }
 
+    /// Arestas que chegam neste vértice vindas de `origin_key`, como `get_edges_to`.
+    pub fn get_back_edges_from(&self, origin_key: i32) -> Option<&Vec<Edge>> {
+        self.back_edges.get(&(self.key, origin_key))
+    }
+
     /// Retorna uma referência imutável ao `HashMap` de arestas.
     ///
     /// # Exemplo
@@ -83,6 +101,7 @@ impl Vertice {
     /// let edges_map_mut = vertice.edges_hashmap_mut();
     /// ```
     pub fn edges_hashmap_mut(&mut self) -> &mut HashMap<(i32, i32), Vec<Edge>> {
+        self.sorted_edges = None;
         &mut self.edges
     }
 
@@ -98,6 +117,26 @@ impl Vertice {
         self.edges.values().flat_map(|vec| vec.clone()).collect()
     }
 
+    /// Retorna todas as arestas sucessoras em ordem determinística.
+    ///
+    /// Ordena pela chave de destino e, em caso de empate (arestas paralelas), pelo id.
+    /// Depois de `sort_edges`, apenas copia a ordem guardada.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// let vertice = Vertice::new(1);
+    /// let sorted_edges = vertice.edges_sorted();
+    /// ```
+    pub fn edges_sorted(&self) -> Vec<Edge> {
+        if let Some(sorted) = &self.sorted_edges {
+            return sorted.clone();
+        }
+        let mut edges = self.edges_vec();
+        edges.sort_by_key(|e| (e.destiny_key(), e.id()));
+        edges
+    }
+
     /// Retorna referências imutáveis para todas as arestas.
     ///
     /// # Exemplo
@@ -110,6 +149,18 @@ impl Vertice {
         self.edges.values().flat_map(|vec| vec.iter()).collect()
     }
 
+    /// Percorre as arestas que chegam no vértice sem copiá-las.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// let vertice = Vertice::new(1);
+    /// let origins: Vec<i32> = vertice.back_edges_vec_ref().map(|e| e.origin_key()).collect();
+    /// ```
+    pub fn back_edges_vec_ref(&self) -> impl Iterator<Item = &Edge> {
+        self.back_edges.values().flat_map(|vec| vec.iter())
+    }
+
     /// Retorna referências mutáveis para todas as arestas.
     ///
     /// # Exemplo
@@ -119,12 +170,24 @@ impl Vertice {
     /// let all_edges_mut = vertice.edges_vec_mut();
     /// ```
     pub fn edges_vec_mut(&mut self) -> Vec<&mut Edge> {
+        self.sorted_edges = None;
         self.edges
             .values_mut()
             .flat_map(|vec| vec.iter_mut())
             .collect()
     }
 
+    /// Retorna referências mutáveis para todas as arestas que chegam no vértice.
+    ///
+    /// São cópias das arestas guardadas nos vértices de origem: alterações aqui precisam
+    /// ser repetidas lá (ver `edges_vec_mut`).
+    pub fn back_edges_vec_mut(&mut self) -> Vec<&mut Edge> {
+        self.back_edges
+            .values_mut()
+            .flat_map(|vec| vec.iter_mut())
+            .collect()
+    }
+
     /// Adiciona uma aresta ao `HashMap`. Permite múltiplas arestas para o mesmo destino.
     ///
     /// # Argumentos
@@ -139,6 +202,7 @@ impl Vertice {
     /// vertice.add_edge(edge);
     /// ```
     pub fn add_edge(&mut self, edge: Edge) {
+        self.sorted_edges = None;
         let key = (self.key, edge.destiny_key());
         self.edges.entry(key).or_insert_with(Vec::new).push(edge);
     }
@@ -170,6 +234,7 @@ impl Vertice {
     }
 
     pub fn remove_edge(&mut self, e: Edge) {
+        self.sorted_edges = None;
         let (v, w) = (e.origin_key(), e.destiny_key());
 
         if let Some((_, edges)) = self.edges.remove_entry(&(v, w)) {
@@ -183,6 +248,100 @@ impl Vertice {
             }
         }
     }
+    /// Quantidade de arestas que saem do vértice (arestas paralelas contam separadamente).
+    pub fn out_degree(&self) -> usize {
+        self.edges.values().map(|vec| vec.len()).sum()
+    }
+
+    /// Quantidade de arestas que chegam no vértice (arestas paralelas contam separadamente).
+    pub fn in_degree(&self) -> usize {
+        self.back_edges.values().map(|vec| vec.len()).sum()
+    }
+
+    /// Remove a aresta sucessora com o id dado, retornando-a se existir.
+    pub fn remove_edge_by_id(&mut self, destiny_key: i32, id: usize) -> Option<Edge> {
+        self.sorted_edges = None;
+        Self::remove_from(&mut self.edges, (self.key, destiny_key), id)
+    }
+
+    /// Remove a aresta predecessora com o id dado, retornando-a se existir.
+    pub fn remove_back_edge_by_id(&mut self, origin_key: i32, id: usize) -> Option<Edge> {
+        Self::remove_from(&mut self.back_edges, (self.key, origin_key), id)
+    }
+
+    /// Inverte a direção de todas as arestas do vértice.
+    ///
+    /// As sucessoras viram predecessoras e vice-versa; id e peso são mantidos.
+    /// Só deixa o grafo consistente se for aplicado a todos os vértices.
+    pub fn reverse(&mut self) {
+        self.sorted_edges = None;
+        std::mem::swap(&mut self.edges, &mut self.back_edges);
+        for edge in self
+            .edges
+            .values_mut()
+            .chain(self.back_edges.values_mut())
+            .flat_map(|vec| vec.iter_mut())
+        {
+            *edge = edge.rewired(edge.destiny_key(), edge.origin_key());
+        }
+    }
+
+    /// Altera o peso da aresta sucessora com o id dado. Retorna `false` se ela não existir.
+    pub fn set_edge_weight_by_id(&mut self, destiny_key: i32, id: usize, weight: i32) -> bool {
+        self.sorted_edges = None;
+        Self::set_weight_in(&mut self.edges, (self.key, destiny_key), id, weight)
+    }
+
+    /// Altera o peso da aresta predecessora com o id dado. Retorna `false` se ela não existir.
+    pub fn set_back_edge_weight_by_id(&mut self, origin_key: i32, id: usize, weight: i32) -> bool {
+        Self::set_weight_in(&mut self.back_edges, (self.key, origin_key), id, weight)
+    }
+
+    /// Ordena por id cada vetor de arestas (sucessoras e predecessoras) deste vértice e guarda
+    /// as sucessoras em ordem (destino, id), lidas por `sorted_edges_ref` sem ordenar de novo.
+    ///
+    /// A ordem guardada é descartada por qualquer método que altere as arestas sucessoras.
+    pub fn sort_edges(&mut self) {
+        for edges in self.edges.values_mut().chain(self.back_edges.values_mut()) {
+            edges.sort_by_key(|e| e.id());
+        }
+        self.sorted_edges = None;
+        self.sorted_edges = Some(self.edges_sorted());
+    }
+
+    /// Arestas sucessoras em ordem (destino, id), se `sort_edges` rodou desde a última
+    /// alteração; `None` caso contrário.
+    pub fn sorted_edges_ref(&self) -> Option<&[Edge]> {
+        self.sorted_edges.as_deref()
+    }
+
+    fn set_weight_in(
+        map: &mut HashMap<(i32, i32), Vec<Edge>>,
+        key: (i32, i32),
+        id: usize,
+        weight: i32,
+    ) -> bool {
+        let Some(edge) = map.get_mut(&key).and_then(|v| v.iter_mut().find(|e| e.id() == id)) else {
+            return false;
+        };
+        edge.set_weight(weight);
+        true
+    }
+
+    fn remove_from(
+        map: &mut HashMap<(i32, i32), Vec<Edge>>,
+        key: (i32, i32),
+        id: usize,
+    ) -> Option<Edge> {
+        let edges = map.get_mut(&key)?;
+        let index = edges.iter().position(|e| e.id() == id)?;
+        let removed = edges.remove(index);
+        if edges.is_empty() {
+            map.remove(&key);
+        }
+        Some(removed)
+    }
+
     /// Retorna todas as arestas que saem deste vértice.
     /// 
     /// tuple.0 contém as arestas que saem do vértice
@@ -228,3 +387,40 @@ impl Vertice {
         edges
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edges_sorted_orders_by_destiny_then_id() {
+        let mut vertice = Vertice::new(0);
+        for w in [5, 2, 9, 2, 7, 5] {
+            vertice.add_edge(Edge::new(0, w));
+        }
+        let sorted = vertice.edges_sorted();
+        let destinies: Vec<i32> = sorted.iter().map(|e| e.destiny_key()).collect();
+        assert_eq!(destinies, vec![2, 2, 5, 5, 7, 9]);
+        // Paralelas ficam na ordem de criação (id crescente)
+        assert!(sorted[0].id() < sorted[1].id());
+        assert!(sorted[2].id() < sorted[3].id());
+        for _ in 0..10 {
+            assert_eq!(vertice.edges_sorted(), sorted);
+        }
+    }
+
+    #[test]
+    fn back_edges_vec_ref_borrows_the_same_edges() {
+        let mut vertice = Vertice::new(3);
+        for v in [1, 4, 1, 3] {
+            vertice.add_back_edge(Edge::new(v, 3));
+        }
+        let mut borrowed: Vec<Edge> = vertice.back_edges_vec_ref().cloned().collect();
+        let mut cloned = vertice.back_edges_hashmap();
+        borrowed.sort_by_key(|e| e.id());
+        cloned.sort_by_key(|e| e.id());
+        assert_eq!(borrowed, cloned);
+        assert_eq!(vertice.back_edges_vec_ref().count(), vertice.in_degree());
+        assert_eq!(Vertice::new(0).back_edges_vec_ref().count(), 0);
+    }
+}
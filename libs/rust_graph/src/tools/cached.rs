@@ -0,0 +1,98 @@
+/// Memoizes a value computed from a graph, invalidated by the graph's `version()`
+///
+/// The stored value is tied to the graph instance it was computed from (`instance_id()`),
+/// so asking with another graph, or with a clone, recomputes even if the versions match.
+///
+/// ```ignore
+/// let mut diameter = Cached::new();
+/// let d = *diameter.get_or_compute(&graph, |g| g.weighted_diameter());
+/// ```
+pub struct Cached<T> {
+    value: Option<T>,
+    instance: u64,
+    version: u64,
+}
+
+#[allow(unused)]
+impl<T> Cached<T> {
+    /// Creates an empty cache
+    pub fn new() -> Self {
+        Cached {
+            value: None,
+            instance: 0,
+            version: 0,
+        }
+    }
+
+    /// Returns the cached value, recomputing it if the graph changed since it was stored
+    pub fn get_or_compute<F>(&mut self, graph: &crate::DiGraph, compute: F) -> &T
+    where
+        F: FnOnce(&crate::DiGraph) -> T,
+    {
+        if !self.is_fresh(graph) {
+            self.value = Some(compute(graph));
+            self.instance = graph.instance_id();
+            self.version = graph.version();
+        }
+        self.value.as_ref().unwrap()
+    }
+
+    /// Checks if there is a stored value computed from this graph at its current version
+    pub fn is_fresh(&self, graph: &crate::DiGraph) -> bool {
+        self.value.is_some()
+            && self.instance == graph.instance_id()
+            && self.version == graph.version()
+    }
+
+    /// Discards the stored value
+    pub fn invalidate(&mut self) {
+        self.value = None;
+    }
+}
+
+impl<T> Default for Cached<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graph_lib::edge::Edge, DiGraph};
+
+    #[test]
+    fn add_edge_triggers_recompute() {
+        let mut graph = DiGraph::from_edge_list(&[(1, 2)]);
+        let mut edges = Cached::new();
+        let mut calls = 0;
+        let mut count = |g: &DiGraph| {
+            calls += 1;
+            g.edges_length()
+        };
+        assert_eq!(*edges.get_or_compute(&graph, &mut count), 1);
+        assert_eq!(*edges.get_or_compute(&graph, &mut count), 1);
+        assert!(edges.is_fresh(&graph));
+
+        graph.add_edge(Edge::new(2, 3));
+        assert!(!edges.is_fresh(&graph));
+        assert_eq!(*edges.get_or_compute(&graph, &mut count), 2);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn other_graph_with_same_version_is_not_fresh() {
+        let original = DiGraph::from_edge_list(&[(1, 2)]);
+        let mut copy = original.clone();
+        copy.add_edge(Edge::new(2, 1));
+        let mut other = original.clone();
+        other.add_edge(Edge::new(1, 2));
+        assert_eq!(copy.version(), other.version());
+
+        let mut edges = Cached::new();
+        edges.get_or_compute(&copy, |g| g.edges_length());
+        assert!(edges.is_fresh(&copy));
+        assert!(!edges.is_fresh(&other));
+        assert!(!edges.is_fresh(&original));
+    }
+}
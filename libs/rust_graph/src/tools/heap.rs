@@ -34,8 +34,14 @@ where T:  Clone,
         self.heap.len()
     }
 
+    /// Returns `true` if the heap has no elements
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Same as `is_empty`, kept for existing callers
     pub fn empty(&self) -> bool {
-        self.heap.len() == 0
+        self.is_empty()
     }
 
     /// Get the parent index and its value
@@ -120,3 +126,61 @@ where T:  Clone,
         self.heap.get(0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn peek_returns_the_minimum_without_removing_it() {
+        let mut heap = HeapMin::new(|a: &i32, b: &i32| a.cmp(b));
+        assert_eq!(heap.peek(), None);
+        for value in [5, 3, 8, 1, 9] {
+            heap.insert(value);
+        }
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.len(), 5);
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.peek(), Some(&3));
+    }
+
+    #[test]
+    fn len_tracks_inserts_and_pops() {
+        let mut heap = HeapMin::with_capacity(4, |a: &i32, b: &i32| b.cmp(a));
+        assert!(heap.is_empty() && heap.empty());
+        heap.insert(2);
+        heap.insert(7);
+        assert_eq!((heap.len(), heap.is_empty()), (2, false));
+        assert_eq!(heap.pop(), Some(7));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), None);
+        assert_eq!(heap.len(), 0);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn pops_match_a_sorted_vec() {
+        let mut rng = StdRng::seed_from_u64(450);
+        let mut heap = HeapMin::new(|a: &(i32, usize), b: &(i32, usize)| a.cmp(b));
+        let mut reference: Vec<(i32, usize)> = Vec::new();
+        for i in 0..2_000 {
+            if rng.gen_bool(0.6) {
+                let value = (rng.gen_range(-50..50), i);
+                heap.insert(value);
+                reference.push(value);
+            } else {
+                reference.sort();
+                let expected = if reference.is_empty() {
+                    None
+                } else {
+                    Some(reference.remove(0))
+                };
+                assert_eq!(heap.peek().copied(), expected);
+                assert_eq!(heap.pop(), expected);
+            }
+            assert_eq!(heap.len(), reference.len());
+        }
+    }
+}
@@ -134,6 +134,100 @@ impl std::fmt::Display for Infinity {
     }
 }
 
+/// Floating-point counterpart of `Infinity`, for distances over `f64` weights.
+///
+/// `NaN` and `+∞` are never stored as `Number`: `new` turns them into `Infinite`, so a
+/// `NaN` weight behaves like a missing edge. This keeps the ordering total, with
+/// `Infinite` greater than every finite value.
+#[derive(Debug, Clone, Copy)]
+pub enum InfinityF64 {
+    Infinite,
+    Number(f64),
+}
+
+#[allow(unused)]
+impl InfinityF64 {
+    pub fn new(val: f64) -> Self {
+        if val.is_nan() || val == f64::INFINITY {
+            InfinityF64::Infinite
+        } else {
+            InfinityF64::Number(val)
+        }
+    }
+
+    pub fn infinite() -> Self {
+        InfinityF64::Infinite
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        matches!(self, InfinityF64::Infinite)
+    }
+
+    pub fn unwrap(&self) -> f64 {
+        match self {
+            InfinityF64::Number(val) => *val,
+            InfinityF64::Infinite => panic!("Cannot unwrap infinite value"),
+        }
+    }
+}
+
+impl PartialEq for InfinityF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for InfinityF64 {}
+
+impl Ord for InfinityF64 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (InfinityF64::Infinite, InfinityF64::Infinite) => std::cmp::Ordering::Equal,
+            (InfinityF64::Infinite, InfinityF64::Number(_)) => std::cmp::Ordering::Greater,
+            (InfinityF64::Number(_), InfinityF64::Infinite) => std::cmp::Ordering::Less,
+            (InfinityF64::Number(a), InfinityF64::Number(b)) => a.total_cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for InfinityF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for InfinityF64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        match (self, other) {
+            (InfinityF64::Infinite, _) | (_, InfinityF64::Infinite) => InfinityF64::Infinite,
+            (InfinityF64::Number(a), InfinityF64::Number(b)) => InfinityF64::new(a + b),
+        }
+    }
+}
+
+impl Sub for InfinityF64 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        match (self, other) {
+            (InfinityF64::Infinite, _) => InfinityF64::Infinite,
+            (_, InfinityF64::Infinite) => InfinityF64::Number(0.0),
+            (InfinityF64::Number(a), InfinityF64::Number(b)) => InfinityF64::new(a - b),
+        }
+    }
+}
+
+impl std::fmt::Display for InfinityF64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InfinityF64::Infinite => write!(f, "∞"),
+            InfinityF64::Number(val) => write!(f, "{}", val),
+        }
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -179,4 +273,73 @@ impl std::fmt::Display for Infinity {
 //     }
 
 
-// }
\ No newline at end of file
+// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f64_infinite_is_greater_than_every_number() {
+        let values = [
+            InfinityF64::new(f64::MAX),
+            InfinityF64::new(-1.5),
+            InfinityF64::new(f64::MIN),
+            InfinityF64::new(0.25),
+        ];
+        for value in values {
+            assert!(InfinityF64::Infinite > value);
+            assert!(value < InfinityF64::infinite());
+        }
+        assert!(InfinityF64::new(-1.5) < InfinityF64::new(0.25));
+        assert_eq!(InfinityF64::Infinite, InfinityF64::Infinite);
+    }
+
+    #[test]
+    fn f64_nan_and_positive_infinity_become_infinite() {
+        assert!(InfinityF64::new(f64::NAN).is_infinite());
+        assert!(InfinityF64::new(f64::INFINITY).is_infinite());
+        assert_eq!(
+            InfinityF64::new(f64::NEG_INFINITY),
+            InfinityF64::Number(f64::NEG_INFINITY)
+        );
+        // Estouro vira +∞, e -∞ - (-∞) daria NaN
+        assert!((InfinityF64::new(f64::MAX) + InfinityF64::new(f64::MAX)).is_infinite());
+        let minus_infinity = InfinityF64::new(f64::NEG_INFINITY);
+        assert!((minus_infinity - minus_infinity).is_infinite());
+    }
+
+    #[test]
+    fn f64_addition_and_subtraction() {
+        assert_eq!(
+            InfinityF64::new(1.5) + InfinityF64::new(2.25),
+            InfinityF64::new(3.75)
+        );
+        assert_eq!(
+            InfinityF64::Infinite + InfinityF64::new(2.0),
+            InfinityF64::Infinite
+        );
+        assert_eq!(
+            InfinityF64::new(2.0) + InfinityF64::Infinite,
+            InfinityF64::Infinite
+        );
+        assert_eq!(
+            InfinityF64::new(5.0) - InfinityF64::new(1.5),
+            InfinityF64::new(3.5)
+        );
+        assert_eq!(
+            InfinityF64::Infinite - InfinityF64::new(1.0),
+            InfinityF64::Infinite
+        );
+        assert_eq!(
+            InfinityF64::new(1.0) - InfinityF64::Infinite,
+            InfinityF64::Number(0.0)
+        );
+        assert_eq!(InfinityF64::new(2.5).unwrap(), 2.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn f64_unwrap_infinite_panics() {
+        InfinityF64::Infinite.unwrap();
+    }
+}
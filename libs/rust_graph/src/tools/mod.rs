@@ -1,2 +1,3 @@
+pub mod cached;
 pub mod heap;
 pub mod inifinity;
\ No newline at end of file